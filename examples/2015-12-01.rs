@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{input::Input, puzzle::PuzzleId, Part, Solution};
 
 /// A collection of instructions to move between floors.
 #[derive(Debug, derive_more::Deref)]
@@ -61,7 +61,8 @@ impl Solution for Day {
 
 /// Run Part 1 and Part 2.
 fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("./inputs/2015-12-01.txt"))?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 1), Part::One)?;
+    let mut day = Day::from_str(&input)?;
     let part1 = day.solve(Part::One)?;
     let part2 = day.solve(Part::Two)?;
 
@@ -2,10 +2,11 @@
 
 use std::{marker::PhantomData, str::FromStr};
 
-use aoc_ornaments::{spatial::{Direction, Position, UniquePositions}, Part, Solution};
+use aoc_ornaments::{spatial::{Direction, Position, SparseGrid}, input::Input, puzzle::PuzzleId, Part, Solution};
 
+/// presents delivered to each house, on a plane with no edges
 #[derive(Debug)]
-pub struct Day<P>(UniquePositions, PhantomData<P>);
+pub struct Day<P>(SparseGrid<u32>, PhantomData<P>);
 
 #[derive(Debug)]
 pub struct Part1;
@@ -14,7 +15,7 @@ pub struct Part1;
 pub struct Part2;
 
 impl<P> std::ops::Deref for Day<P> {
-    type Target = UniquePositions;
+    type Target = SparseGrid<u32>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -25,17 +26,17 @@ impl FromStr for Day<Part1> {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let mut visited = UniquePositions::new();
+        let mut houses = SparseGrid::default();
         let mut position = Position::ZERO;
 
-        visited.insert(position);
+        *houses.entry(position) += 1;
 
         for c in input.chars() {
             position += Direction::parse(c)?.to_offset();
-            visited.insert(position);
+            *houses.entry(position) += 1;
         }
 
-        Ok(Self(visited, PhantomData))
+        Ok(Self(houses, PhantomData))
     }
 }
 
@@ -43,27 +44,21 @@ impl FromStr for Day<Part2> {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let mut santa_visited = UniquePositions::new();
-        let mut robot_visited = UniquePositions::new();
-        let mut santa_position = Position::ZERO;
-        let mut robot_position = Position::ZERO;
+        let mut houses = SparseGrid::default();
+        // santa, then the robot
+        let mut positions = [Position::ZERO; 2];
 
-        santa_visited.insert(santa_position);
-        robot_visited.insert(robot_position);
+        *houses.entry(Position::ZERO) += 2;
 
         input.chars()
             .enumerate()
             .for_each(|(i, c)| {
-                if i % 2 == 0 {
-                    santa_position += Direction::parse(c).expect("valid char").to_offset();
-                    santa_visited.insert(santa_position);
-                } else {
-                    robot_position += Direction::parse(c).expect("valid char").to_offset();
-                    robot_visited.insert(robot_position);
-                }
+                let position = &mut positions[i % 2];
+                *position += Direction::parse(c).expect("valid char").to_offset();
+                *houses.entry(*position) += 1;
             });
 
-        Ok(Self(santa_visited.union(&robot_visited).copied().collect(), PhantomData))
+        Ok(Self(houses, PhantomData))
     }
 }
 
@@ -78,9 +73,9 @@ impl<P> Solution for Day<P> where Day<P>: FromStr {
 
 /// Run Part 1 and Part 2.
 fn main() -> miette::Result<()> {
-    let input = include_str!("./inputs/2015-12-03.txt");
-    let mut day_part1 = Day::<Part1>::from_str(input)?;
-    let mut day_part2 = Day::<Part2>::from_str(input)?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 3), Part::One)?;
+    let mut day_part1 = Day::<Part1>::from_str(&input)?;
+    let mut day_part2 = Day::<Part2>::from_str(&input)?;
     let part1 = day_part1.solve(Part::One)?;
     let part2 = day_part2.solve(Part::Two)?;
 
@@ -104,6 +99,11 @@ mod tests {
         assert_eq!(Day::<Part1>::from_str(input).unwrap().solve(Part::One).unwrap(), expected.to_string());
     }
 
+    #[test]
+    fn test_houses() {
+        assert_eq!(Day::<Part1>::from_str("^>v<").unwrap().to_string(), "11\n21");
+    }
+
     #[rstest]
     #[case("^v", 3)]
     #[case("^>v<", 3)]
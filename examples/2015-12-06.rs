@@ -2,7 +2,7 @@
 
 use std::{ops::Not, str::FromStr};
 
-use aoc_ornaments::{spatial::Grid, Part, Solution};
+use aoc_ornaments::{spatial::Grid, input::Input, puzzle::PuzzleId, Part, Solution};
 
 #[derive(Debug, derive_more::Deref, Clone, Copy, PartialEq)]
 pub struct Part1(bool);
@@ -166,9 +166,9 @@ impl Solution for Day<Part2> {
 
 /// Run Part 1 and Part 2.
 fn main() -> miette::Result<()> {
-    let input = include_str!("./inputs/2015-12-06.txt");
-    let mut day_part1 = Day::<Part1>::from_str(input)?;
-    let mut day_part2 = Day::<Part2>::from_str(input)?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 6), Part::One)?;
+    let mut day_part1 = Day::<Part1>::from_str(&input)?;
+    let mut day_part2 = Day::<Part2>::from_str(&input)?;
     let part1 = day_part1.solve(Part::One)?;
     let part2 = day_part2.solve(Part::Two)?;
 
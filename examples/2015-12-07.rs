@@ -1,10 +1,10 @@
 //! Day 7: Some Assembly Required
 
 use std::str::FromStr;
-use aoc_ornaments::{bits::{LogicCircuit, LogicGate, Operand, Wires}, Part, Solution};
+use aoc_ornaments::{bits::{LogicCircuit, LogicGate, Operand, Wires}, input::Input, puzzle::PuzzleId, Part, Solution};
 use miette::Context;
 
-#[derive(Debug, derive_more::Deref, derive_more::DerefMut)]
+#[derive(Debug, Clone, derive_more::Deref, derive_more::DerefMut)]
 struct Day(LogicCircuit<String, Operand>);
 
 impl FromStr for Day {
@@ -50,26 +50,29 @@ impl Solution for Day {
     type Output = u16;
 
     fn solve(&mut self, part: Part) -> miette::Result<String> {
+        // keep the initial state around for part 2
+        let initial = self.clone();
         self.execute()?;
 
         if let Part::One = part {
-            return self.resolve_wire("a").map(|v| v.to_string());
+            return Ok(self.resolve_wire("a")?.to_string());
         }
 
         let part1_result = self.resolve_wire("a")?.to_string();
         // Reset circuit to initial state
-        *self = Day::from_str(include_str!("./inputs/2015-12-07.txt"))?;
+        *self = initial;
 
         // Override wire b with part1's result
         self.wires.insert("b".to_string(), part1_result);
         
         self.execute()?;
-        self.resolve_wire("a").map(|v| v.to_string())
+        Ok(self.resolve_wire("a")?.to_string())
     }
 }
 
 fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("./inputs/2015-12-07.txt"))?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 7), Part::One)?;
+    let mut day = Day::from_str(&input)?;
     let part1 = day.solve(Part::One)?;
     let part2 = day.solve(Part::Two)?;
 
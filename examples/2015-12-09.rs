@@ -4,6 +4,8 @@ use std::str::FromStr;
 
 use aoc_ornaments::{
     graph::{Distances, TravelingSales},
+    input::Input,
+    puzzle::PuzzleId,
     Part, Solution,
 };
 
@@ -53,9 +55,9 @@ impl Solution for Day {
 }
 
 fn main() -> miette::Result<()> {
-    let input = include_str!("./inputs/2015-12-09.txt");
-    let part1 = Day::from_str(input)?.solve(Part::One)?;
-    let part2 = Day::from_str(input)?.solve(Part::Two)?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 9), Part::One)?;
+    let part1 = Day::from_str(&input)?.solve(Part::One)?;
+    let part2 = Day::from_str(&input)?.solve(Part::Two)?;
 
     println!("Part 1: {}", part1);
     println!("Part 2: {}", part2);
@@ -67,27 +69,12 @@ fn main() -> miette::Result<()> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        let input = "London to Dublin = 464
+    aoc_ornaments::examples!(Day {
+        shortest: Part::One, "London to Dublin = 464
 London to Belfast = 518
-Dublin to Belfast = 141";
-
-        let mut day = Day::from_str(input).unwrap();
-        let result = day.solve(Part::One).unwrap();
-
-        assert_eq!(result, "605");
-    }
-
-    #[test]
-    fn test_part2() {
-        let input = "London to Dublin = 464
+Dublin to Belfast = 141" => 605,
+        longest: Part::Two, "London to Dublin = 464
 London to Belfast = 518
-Dublin to Belfast = 141";
-
-        let mut day = Day::from_str(input).unwrap();
-        let result = day.solve(Part::Two).unwrap();
-
-        assert_eq!(result, "982");
-    }
+Dublin to Belfast = 141" => 982,
+    });
 }
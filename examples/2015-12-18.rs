@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{spatial::{Grid, Position}, Part, ArgSolution};
+use aoc_ornaments::{spatial::{Grid, Position}, input::Input, puzzle::PuzzleId, Part, ArgSolution, DefaultArgs};
 
 #[derive(Debug, derive_more::Deref, derive_more::DerefMut)]
 struct Day(Grid<bool>);
@@ -18,7 +18,7 @@ impl FromStr for Day {
 impl Day {
     fn step(&mut self, count: usize) {
         for _ in 0..count {
-            let mut next = Grid(self.clone());
+            let mut next = self.0.clone();
 
             self.walk(|pos| {
                 let neighbors = self.get_all_neighbors(pos).iter().filter(|(_pos, state)| *state).count();
@@ -47,25 +47,7 @@ impl Day {
     }
 
     fn sum(&self) -> usize {
-        self.iter().map(|row| row.iter().filter(|&&b| b).count()).sum()
-    }
-
-    /// used for debugging
-    #[allow(dead_code)]
-    fn print(&self) {
-        for row in self.iter() {
-            for &light in row {
-                print!("{}", if light { '#' } else { '.' });
-            }
-            println!();
-        }
-    }
-}
-
-impl ToString for Day {
-    /// used for debugging
-    fn to_string(&self) -> String {
-        self.iter().map(|row| row.iter().map(|&b| if b { '#' } else { '.' }).collect::<String>()).collect::<Vec<String>>().join("\n")
+        self.cells().iter().filter(|&&b| b).count()
     }
 }
 
@@ -82,7 +64,7 @@ impl ArgSolution<usize> for Day {
         self.always_on();
 
         for _ in 0..count {
-            let mut next = Grid(self.clone());
+            let mut next = self.0.clone();
 
             self.walk(|pos| {
                 let neighbors = self.get_all_neighbors(pos).iter().filter(|(_pos, state)| *state).count();
@@ -104,12 +86,25 @@ impl ArgSolution<usize> for Day {
     }
 }
 
+impl DefaultArgs<usize> for Day {
+    /// steps of animation
+    fn args(_part: Part) -> usize {
+        100
+    }
+
+    fn example_args(part: Part) -> usize {
+        match part {
+            Part::One => 4,
+            _ => 5,
+        }
+    }
+}
+
 fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("./inputs/2015-12-18.txt"))?;
-    let part1 = day.solve(Part::One, 100)?;
-    // state is dirty after part1, need to reset
-    let mut day = Day::from_str(include_str!("./inputs/2015-12-18.txt"))?;
-    let part2 = day.solve(Part::Two, 100)?;
+    let input = Input::default().load(&PuzzleId::aoc(2015, 18), Part::One)?;
+    // part1 steps the grid, so each part starts from a fresh parse
+    let part1 = Day::solve_fresh(&input, Part::One, Day::args(Part::One))?;
+    let part2 = Day::solve_fresh(&input, Part::Two, Day::args(Part::Two))?;
 
     println!("Part 1: {}", part1);
     println!("Part 2: {}", part2);
@@ -162,7 +157,6 @@ mod tests {
 #.#..#
 ####..").unwrap();
         day.step(steps);
-        // day.print();
 
         assert_eq!(expected, day.to_string());
     }
@@ -177,7 +171,7 @@ mod tests {
 ####..";
 
         let mut day = Day::from_str(input).unwrap();
-        let result = day.solve(Part::One, 4).unwrap();
+        let result = day.solve(Part::One, Day::example_args(Part::One)).unwrap();
 
         assert_eq!(result, "4");
     }
@@ -241,7 +235,7 @@ mod tests {
 ####.#";
 
         let mut day = Day::from_str(input).unwrap();
-        let result = day.solve(Part::Two, 5).unwrap();
+        let result = day.solve(Part::Two, Day::example_args(Part::Two)).unwrap();
 
         assert_eq!(result, "17");
     }
@@ -1,36 +1,53 @@
 //! Example implementation of a 3-part Everybody Codes solution.
+//!
+//! Each part has its own notes, `everybody_codes_e2024_q01_p1.txt` and so on, which [Notes] parses one at a time.
 
-use std::str::FromStr;
+use aoc_ornaments::{input::Input, puzzle::PuzzleId, Notes, Part, Solution, SolutionResult};
 
-use aoc_ornaments::{Part, Solution};
+/// part 1's notes are a single line, the later parts are a grid
+enum Day {
+    Line(String),
+    Grid(Vec<String>),
+}
 
-struct Day;
+impl Notes<'_> for Day {
+    fn parse_notes(notes: &str, part: Part) -> SolutionResult<Self> {
+        Ok(match part {
+            Part::One => Self::Line(notes.trim().to_string()),
+            Part::Two | Part::Three => Self::Grid(notes.lines().map(String::from).collect()),
+        })
+    }
+}
 
 impl Solution for Day {
-    type Output = String;
+    type Output = usize;
 
-    fn part3(&mut self) -> aoc_ornaments::SolutionResult<Self::Output> {
-        todo!()
+    fn part1(&mut self) -> SolutionResult<Self::Output> {
+        match self {
+            Self::Line(line) => Ok(line.len()),
+            Self::Grid(rows) => Ok(rows.concat().len()),
+        }
     }
-}
 
-impl FromStr for Day {
-    type Err = miette::Error;
+    fn part2(&mut self) -> SolutionResult<Self::Output> {
+        match self {
+            Self::Line(_) => Ok(1),
+            Self::Grid(rows) => Ok(rows.len()),
+        }
+    }
 
-    fn from_str(_input: &str) -> miette::Result<Self> {
-        Ok(Self)
+    fn part3(&mut self) -> SolutionResult<Self::Output> {
+        self.part1()
     }
 }
 
 fn main() -> miette::Result<()> {
-    let mut day = Day::from_str("aoc")?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-    let part3 = day.solve(Part::Three)?;
+    let id = PuzzleId::everybody(2024, 1);
 
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-    println!("Part 3: {}", part3);
+    for &part in id.parts() {
+        let notes = Input::default().load(&id, part)?;
+        println!("{part}: {}", Day::solve_notes(&notes, part)?);
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,46 +1,47 @@
 //! This example demonstrates how to use the `nom` parser combinators library to parse input data.
+//!
+//! nom is zero-copy, so the parsed data borrows from the input. [Parse] lets the solution keep those slices.
 
-use std::str::FromStr;
+use aoc_ornaments::{Parse, Part, Solution};
 
-// use aoc_ornaments::Solution;
+struct Day<'a>(Vec<&'a str>);
 
-struct Day(&'static str);
+impl Solution for Day<'_> {
+    type Output = usize;
 
-// impl Solution for Day {
-//     type Output = String;
-
-//     fn part1(&mut self) -> miette::Result<Self::Output> {
-//         Ok("Hello, Rudolph!".into())
-//     }
+    fn part1(&mut self) -> miette::Result<Self::Output> {
+        Ok(self.0.len())
+    }
 
-//     fn part2(&mut self) -> miette::Result<Self::Output> {
-//         Ok("Hello, Santa!".into())
-//     }
-// }
+    fn part2(&mut self) -> miette::Result<Self::Output> {
+        Ok(self.0.iter().map(|line| line.len()).sum())
+    }
+}
 
-impl Day {
-    fn nom_parser(input: &'static str) -> nom::IResult<&'static str, &'static str> {
-        Ok((input, ""))
+impl<'a> Day<'a> {
+    fn nom_parser(input: &'a str) -> nom::IResult<&'a str, Vec<&'a str>> {
+        nom::multi::separated_list0(nom::character::complete::newline, nom::character::complete::alpha1)(input)
     }
 }
 
-impl FromStr for Day {
+impl<'a> Parse<'a> for Day<'a> {
     type Err = miette::Error;
 
-    fn from_str(input: &str) -> miette::Result<Self> {
-        // We leak the input to get a 'static str - this IS a bad practice! shown only for the purpose of this example (to avoid lifetimes & new allocation)
-        let static_input = Box::leak(input.to_string().into_boxed_str());
-
-        // otherwise you will get: error[E0521]: borrowed data escapes outside of associated function
-        let (_, data) = Self::nom_parser(static_input)
-            .map_err(|e| miette::miette!("Failed to parse input: {}", e))?;
+    fn parse(input: &'a str) -> miette::Result<Self> {
+        // no `Box::leak` or `.to_string()` needed, the slices live as long as the input
+        let (_, data) = Self::nom_parser(input)
+            .map_err(|e| miette::miette!("Failed to parse input: {e}"))?;
 
         Ok(Self(data))
     }
 }
 
-fn main() -> miette::Result<()>{
-    Day::from_str("")?;
-    
+fn main() -> miette::Result<()> {
+    let input = String::from("Rudolph\nSanta");
+
+    let mut day = Day::parse(&input)?;
+    println!("Part 1: {}", day.solve(Part::One)?);
+    println!("Part 2: {}", day.solve(Part::Two)?);
+
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,115 @@
+//! Compile-time helpers for aoc-ornaments, used through its macros rather than directly
+
+use std::path::PathBuf;
+
+use proc_macro::{Delimiter, Group, Literal, TokenStream, TokenTree};
+
+/// Adds an rstest `#[case::partN(index)]` for each example in a sidecar file, so every example is its own test
+///
+/// Goes above `#[rstest]` on a function taking the example's index, the file is relative to the crate root:
+///
+/// ```ignore
+/// #[sidecar_cases("src/bin/aoc/2015-12-17.examples")]
+/// #[rstest::rstest]
+/// fn examples(#[case] n: usize) {}
+/// ```
+///
+/// Only the headers are read here, the examples themselves are loaded when the tests run.
+/// A file without any, as a new day starts with, is a single ignored test instead.
+#[proc_macro_attribute]
+pub fn sidecar_cases(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let Some(file) = string_literal(attr) else {
+        return compile_error("expected the sidecar file as a string literal");
+    };
+
+    let path = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join(&file);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => return compile_error(&format!("Failed to read {}: {e}", path.display())),
+    };
+
+    let cases: String = text.lines()
+        .filter(|line| line.starts_with("=== "))
+        .enumerate()
+        .map(|(n, header)| match part(header) {
+            Some(part) => format!("#[case::part{part}({n})]"),
+            None => format!("#[case({n})]"),
+        })
+        .collect();
+
+    // rebuild when the file changes, as include_str! would
+    let tracked = format!("const _: &str = include_str!({});", Literal::string(&path.display().to_string()));
+    let tracked = tracked.parse::<TokenStream>().expect("a valid item");
+
+    // rstest won't take an argument without cases, so a new day's empty file is a single ignored test over none of them
+    if cases.is_empty() {
+        let mut function = item.into_iter().skip_while(|token| !is_fn(token)).skip(1);
+        let (Some(name), Some(TokenTree::Group(args)), Some(TokenTree::Group(body))) = (function.next(), function.next(), function.next()) else {
+            return compile_error("expected a function taking the example's index");
+        };
+        let Some(index) = args.stream().into_iter().find(|token| matches!(token, TokenTree::Ident(_))) else {
+            return compile_error("expected a function taking the example's index");
+        };
+
+        // built from tokens rather than text, as the body may use `$crate`
+        let mut looped: TokenStream = format!("for {index} in ::core::iter::empty::<usize>()").parse().expect("a valid loop");
+        looped.extend([TokenTree::Group(body)]);
+
+        let mut expanded: TokenStream = format!("#[test]\n#[ignore = {}]\nfn {name}()", Literal::string(&format!("no examples in {file} yet")))
+            .parse()
+            .expect("a valid test");
+        expanded.extend([TokenTree::Group(Group::new(Delimiter::Brace, looped))]);
+        expanded.extend(tracked);
+
+        return expanded;
+    }
+
+    let mut tokens = item.into_iter().peekable();
+    let mut expanded = TokenStream::new();
+
+    // the cases go after the other attributes, where rstest will find them
+    while let Some(token) = tokens.next_if(|token| !is_fn(token)) {
+        expanded.extend([token]);
+    }
+
+    expanded.extend(cases.parse::<TokenStream>().expect("cases are valid attributes"));
+    expanded.extend(tokens);
+    expanded.extend(tracked);
+
+    expanded
+}
+
+fn is_fn(token: &TokenTree) -> bool {
+    matches!(token, TokenTree::Ident(ident) if ident.to_string() == "fn")
+}
+
+/// The part number from `=== part N [with ARG] -> ANSWER`
+fn part(header: &str) -> Option<&str> {
+    let part = header.strip_prefix("=== part ")?.split_whitespace().next()?;
+
+    part.chars().all(|c| c.is_ascii_digit()).then_some(part)
+}
+
+/// The contents of a plain string literal, which `macro_rules!` may have wrapped in an invisible group
+fn string_literal(tokens: TokenStream) -> Option<String> {
+    let mut tokens = tokens.into_iter();
+    let token = tokens.next()?;
+
+    if tokens.next().is_some() {
+        return None;
+    }
+
+    match token {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => string_literal(group.stream()),
+        TokenTree::Literal(literal) => {
+            let literal = literal.to_string();
+
+            Some(literal.strip_prefix('"')?.strip_suffix('"')?.to_string())
+        },
+        _ => None,
+    }
+}
+
+fn compile_error(message: &str) -> TokenStream {
+    format!("compile_error!({});", Literal::string(message)).parse().expect("a valid macro call")
+}
@@ -0,0 +1,293 @@
+//! Time parsing and each part separately
+//!
+//! Results can be saved as JSON and compared against later runs, to see whether a change to a shared module helped.
+
+use std::{collections::BTreeMap, path::Path, time::{Duration, Instant}};
+
+use serde_json::{json, Map, Value};
+
+use crate::{cancel::Token, puzzle::PuzzleId, runner::Solver, Part, SolutionResult};
+
+/// Slower than the baseline by more than this fraction is a regression
+pub const THRESHOLD: f64 = 0.10;
+
+/// Summary of repeated measurements
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub runs: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len() % 2 == 0 {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / sorted.len() as u32,
+            runs: sorted.len(),
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "runs": self.runs,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        let nanos = |key: &str| value.get(key)?.as_u64().map(Duration::from_nanos);
+
+        Some(Self {
+            min: nanos("min_ns")?,
+            median: nanos("median_ns")?,
+            mean: nanos("mean_ns")?,
+            runs: value.get("runs")?.as_u64()? as usize,
+        })
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.runs == 1 {
+            return write!(f, "{:?}", self.min);
+        }
+
+        write!(f, "min {:?} / median {:?} / mean {:?}", self.min, self.median, self.mean)
+    }
+}
+
+/// Parsing and solving a single part
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Timing {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Timing {
+    /// median parse + median solve
+    pub fn total(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+impl std::fmt::Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parse {}, solve {}", self.parse, self.solve)
+    }
+}
+
+/// How many times to run each part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bench {
+    /// runs that aren't measured
+    pub warmup: usize,
+    /// runs that are measured, at least one(1)
+    pub runs: usize,
+}
+
+impl Default for Bench {
+    /// a single measured run
+    fn default() -> Self {
+        Self { warmup: 0, runs: 1 }
+    }
+}
+
+impl Bench {
+    pub fn new(warmup: usize, runs: usize) -> Self {
+        Self { warmup, runs: runs.max(1) }
+    }
+
+    /// Parses fresh for every run, so the solution's state is never reused
+    ///
+    /// Each run gets the whole `budget` to itself, see [cancel](crate::cancel)
+    pub fn measure(&self, solver: &Solver, input: &str, part: Part, budget: Option<Duration>) -> SolutionResult<(String, Timing)> {
+        let mut answer = String::new();
+        let mut parses = Vec::with_capacity(self.runs);
+        let mut solves = Vec::with_capacity(self.runs);
+
+        for run in 0..self.warmup + self.runs {
+            let token = budget.map_or_else(Token::new, Token::with_budget);
+            let (parse, solve) = token.scope(|| -> SolutionResult<_> {
+                let start = Instant::now();
+                let mut parsed = solver(input)?;
+                let parsed_at = Instant::now();
+                answer = parsed(part)?;
+
+                Ok((parsed_at - start, parsed_at.elapsed()))
+            })?;
+
+            // whether it gave up at a checkpoint or finished late, it's out of time
+            token.check()?;
+
+            if run >= self.warmup {
+                parses.push(parse);
+                solves.push(solve);
+            }
+        }
+
+        Ok((answer, Timing { parse: Stats::from_samples(&parses), solve: Stats::from_samples(&solves) }))
+    }
+}
+
+/// Timings for many parts, keyed like the saved JSON: `{"2015-12-04": {"1": {...}}}`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Results(pub BTreeMap<(PuzzleId, Part), Timing>);
+
+impl Results {
+    pub fn insert(&mut self, id: &PuzzleId, part: Part, timing: Timing) {
+        self.0.insert((*id, part), timing);
+    }
+
+    pub fn get(&self, id: &PuzzleId, part: Part) -> Option<&Timing> {
+        self.0.get(&(*id, part))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> SolutionResult<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| crate::report(format!("Failed to read {}: {e}", path.display())))?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| crate::report(format!("Invalid benchmark file {}: {e}", path.display())))?;
+
+        let mut results = Self::default();
+
+        for (id, parts) in json.as_object().into_iter().flatten() {
+            for (part, timing) in parts.as_object().into_iter().flatten() {
+                let (Ok(key), Ok(part), Some(parse), Some(solve)) = (
+                    id.parse(),
+                    part.parse(),
+                    timing.get("parse").and_then(Stats::from_json),
+                    timing.get("solve").and_then(Stats::from_json),
+                ) else {
+                    return Err(crate::report(format!("Invalid timing for {id} part {part} in {}", path.display())));
+                };
+
+                results.0.insert((key, part), Timing { parse, solve });
+            }
+        }
+
+        Ok(results)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> SolutionResult<()> {
+        let path = path.as_ref();
+        let mut json = Map::new();
+
+        for ((id, part), timing) in &self.0 {
+            let parts = json.entry(id.to_string()).or_insert_with(|| Value::Object(Map::new()));
+            parts[part.number().to_string()] = json!({ "parse": timing.parse.to_json(), "solve": timing.solve.to_json() });
+        }
+
+        let text = serde_json::to_string_pretty(&Value::Object(json)).expect("json values always serialize");
+        std::fs::write(path, text + "\n")
+            .map_err(|e| crate::report(format!("Failed to write {}: {e}", path.display())))
+    }
+}
+
+/// How a timing compares to its baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Change {
+    /// positive is slower
+    pub fraction: f64,
+}
+
+impl Change {
+    /// compares the total of the medians
+    pub fn between(baseline: &Timing, current: &Timing) -> Self {
+        let before = baseline.total().as_secs_f64();
+        let after = current.total().as_secs_f64();
+
+        let fraction = if before == 0.0 { 0.0 } else { (after - before) / before };
+
+        Self { fraction }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        self.fraction > THRESHOLD
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.1}% vs baseline", self.fraction * 100.0)?;
+
+        if self.is_regression() {
+            write!(f, ", REGRESSION")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3), mean: ms(3), runs: 3 });
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(1)]);
+        assert_eq!(stats.median, Duration::from_micros(1500));
+        assert_eq!(stats.mean, ms(2));
+    }
+
+    #[test]
+    fn test_measure() {
+        let solver: Solver = Box::new(|input: &str| {
+            let len = input.len();
+            Ok(Box::new(move |_part| Ok(len.to_string())))
+        });
+
+        let (answer, timing) = Bench::new(2, 3).measure(&solver, "abc", Part::One, None).unwrap();
+        assert_eq!(answer, "3");
+        assert_eq!(timing.parse.runs, 3);
+        assert_eq!(timing.solve.runs, 3);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let stats = Stats { min: ms(1), median: ms(2), mean: ms(3), runs: 5 };
+        let mut results = Results::default();
+        results.insert(&PuzzleId::aoc(2015, 4), Part::Two, Timing { parse: stats, solve: stats });
+
+        let path = std::env::temp_dir().join("aoc-ornaments-bench.json");
+        results.save(&path).unwrap();
+        assert_eq!(Results::load(&path).unwrap(), results);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_change() {
+        let timing = |n| Timing { parse: Stats::default(), solve: Stats { median: ms(n), ..Default::default() } };
+
+        assert!(Change::between(&timing(100), &timing(120)).is_regression());
+        assert!(!Change::between(&timing(100), &timing(105)).is_regression());
+        assert!(!Change::between(&timing(100), &timing(50)).is_regression());
+        assert_eq!(Change::between(&timing(100), &timing(50)).to_string(), "-50.0% vs baseline");
+    }
+}
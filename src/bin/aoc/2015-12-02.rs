@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution, SolutionError};
 
 use nom::{
     character::complete::{char, u32}, multi::separated_list1, IResult
@@ -27,11 +27,13 @@ impl FromStr for Day {
     /// 
     fn from_str(input: &str) -> miette::Result<Self> {
         let parsed = input.lines()
-            .map(|line| {
-                let (_, (l, w, h)) = Self::parse_dimensions(line).unwrap();
+            .enumerate()
+            .map(|(n, line)| {
+                let (_, (l, w, h)) = Self::parse_dimensions(line)
+                    .map_err(|_| SolutionError::line(input, n, "expected LxWxH"))?;
 
-                (l, w, h)
-            }).collect();
+                Ok((l, w, h))
+            }).collect::<Result<_, SolutionError>>()?;
 
         Ok(Self(parsed))
     }
@@ -85,16 +87,9 @@ impl Solution for Day {
     }
 }
 
-/// Run Part 1 and Part 2.
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-02.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 2))
+        .title(PuzzleId::aoc(2015, 2), "I Was Told There Would Be No Math");
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution, SolutionError};
 
 #[derive(Debug, derive_more::Deref)]
 struct Day(String);
@@ -41,23 +41,16 @@ impl Solution for Day {
 
     /// Find the lowest positive number that, when combined with the input string, produces an MD5 hash with five leading zeros.
     fn part1(&mut self) -> miette::Result<Self::Output> {
-        self.compute(Self::has_five_leading_zeros).ok_or_else(|| miette::miette!("No solution found"))
+        self.compute(Self::has_five_leading_zeros).ok_or_else(|| SolutionError::unsolvable("no hash with five leading zeros").into())
     }
 
     /// Find the lowest positive number that, when combined with the input string, produces an MD5 hash with six leading zeros.
     fn part2(&mut self) -> miette::Result<Self::Output> {
-        self.compute(Self::has_six_leading_zeros).ok_or_else(|| miette::miette!("No solution found"))
+        self.compute(Self::has_six_leading_zeros).ok_or_else(|| SolutionError::unsolvable("no hash with six leading zeros").into())
     }
 }
 
-/// Run Part 1 and Part 2.
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-04.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
-}
\ No newline at end of file
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 4))
+        .title(PuzzleId::aoc(2015, 4), "The Ideal Stocking Stuffer");
+}
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution};
 
 #[derive(Debug, derive_more::Deref)]
 struct Day(Vec<String>);
@@ -82,16 +82,9 @@ impl Solution for Day {
     }
 }
 
-/// Run Part 1 and Part 2.
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-05.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 5))
+        .title(PuzzleId::aoc(2015, 5), "Doesn't He Have Intern-Elves For This?");
 }
 
 #[cfg(test)]
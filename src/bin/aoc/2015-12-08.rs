@@ -1,9 +1,8 @@
 //! Day 8: Matchsticks
 
-use std::fs;
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution};
 
 #[derive(Debug, derive_more::Deref)]
 struct Day(Vec<String>);
@@ -96,22 +95,17 @@ struct StringMetrics {
     mem_len: usize,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // include_str! will add even more escape sequences and break the code
-    let input = fs::read_to_string("../examples/inputs/2015-12-08.txt")?;
-    let mut day = Day::from_str(&input)?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 8))
+        .title(PuzzleId::aoc(2015, 8), "Matchsticks");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use aoc_ornaments::Part;
+
     use rstest::rstest;
 
     #[rstest]
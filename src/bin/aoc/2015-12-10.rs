@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, ArgSolution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, ArgSolution, DefaultArgs};
 use itertools::Itertools;
 
 /// char, count
@@ -81,15 +81,19 @@ impl ToString for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-10.txt"))?;
-    let part1 = day.solve(Part::One, 40)?;
-    let part2 = day.solve(Part::Two, 50)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+impl DefaultArgs<usize> for Day {
+    /// iterations of look-and-say
+    fn args(part: Part) -> usize {
+        match part {
+            Part::One => 40,
+            _ => 50,
+        }
+    }
+}
 
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, usize>(PuzzleId::aoc(2015, 10))
+        .title(PuzzleId::aoc(2015, 10), "Elves Look, Elves Say");
 }
 
 #[cfg(test)]
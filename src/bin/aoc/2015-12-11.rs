@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, Solution};
 
 #[derive(Debug, derive_more::Deref)]
 struct Day(Vec<char>);
@@ -99,15 +99,9 @@ impl Solution for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-11.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-    
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 11))
+        .title(PuzzleId::aoc(2015, 11), "Corporate Policy");
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@
 
 use std::{marker::PhantomData, str::FromStr};
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, Solution, SolutionError};
 use nom::{bytes::complete::take_till, character::complete::{char, digit1}, combinator::{map, opt, recognize}, sequence::pair, IResult};
 use serde_json::Value;
 
@@ -29,20 +29,20 @@ impl FromStr for Day<Text> {
 
     fn from_str(input: &str) -> miette::Result<Self> {
         let mut numbers = Vec::new();
-        let mut input = input;
+        let mut rest = input;
 
-        while !input.is_empty() {
-            match Day::<Text>::find_digit(input) {
+        while !rest.is_empty() {
+            match Day::<Text>::find_digit(rest) {
                 Ok((remainder, _)) => {
                     match Day::<Text>::parse_number(remainder) {
                         Ok((remainder, number)) => {
                             numbers.push(number);
-                            input = remainder;
+                            rest = remainder;
                         },
                         _ => break
                     }
                 },
-                _ => todo!()
+                Err(e) => return Err(aoc_ornaments::nom::error(input, e).into()),
             }
         }
 
@@ -55,7 +55,7 @@ impl FromStr for Day<Jasn> {
 
     fn from_str(input: &str) -> miette::Result<Self> {
         let json: Value = serde_json::from_str(input)
-            .map_err(|e| miette::miette!("Failed to parse JSON: {}", e))?;
+            .map_err(|e| SolutionError::line(input, e.line().saturating_sub(1), e))?;
         
         // Part 1: Just collect all numbers
         let numbers = Day::<Jasn>::ignore_red(&json);
@@ -118,17 +118,10 @@ impl<J> Solution for Day<J> where Day<J>: FromStr {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let input = include_str!("../inputs/2015-12-12.txt");
-    let mut day_part1 = Day::<Text>::from_str(input)?;
-    let mut day_part2 = Day::<Jasn>::from_str(input)?;
-    let part1 = day_part1.solve(Part::One)?;
-    let part2 = day_part2.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution_part::<Day<Text>>(PuzzleId::aoc(2015, 12), Part::One)
+        .solution_part::<Day<Jasn>>(PuzzleId::aoc(2015, 12), Part::Two)
+        .title(PuzzleId::aoc(2015, 12), "JSAbacusFramework.io");
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@
 
 use std::{collections::HashSet, str::FromStr};
 
-use aoc_ornaments::{graph::Distances, Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, graph::Distances, Part, Solution, SolutionError};
 
 type Happiness = Distances<i64>;
 
@@ -16,21 +16,20 @@ impl FromStr for Day {
     fn from_str(input: &str) -> miette::Result<Self> {
         let mut map = Happiness::new();
 
-        input.lines().for_each(|line| {
-            // len() - 1 to remove the period
-            let parts: Vec<&str> = line[..line.len() - 1].split_whitespace().collect();
+        for (line_number, line) in input.lines().enumerate() {
+            let invalid = || SolutionError::line(input, line_number, "expected `A would gain|lose N happiness units by sitting next to B.`");
+            let parts: Vec<&str> = line.strip_suffix('.').ok_or_else(invalid)?.split_whitespace().collect();
 
             match parts.as_slice() {
                 [a, "would", "gain", n, "happiness", "units", "by", "sitting", "next", "to", b,] => {
-                    map.insert((a.to_string(), b.to_string()), n.parse().unwrap());
+                    map.insert((a.to_string(), b.to_string()), n.parse().map_err(|_| invalid())?);
                 },
                 [a, "would", "lose", n, "happiness", "units", "by", "sitting", "next", "to", b,] => {
-                    map.insert((a.to_string(), b.to_string()), -n.parse::<i64>().unwrap());
+                    map.insert((a.to_string(), b.to_string()), -n.parse::<i64>().map_err(|_| invalid())?);
                 },
-                // _ => return Err(miette::MietteError::from("invalid input")),
-                _ => todo!(),
+                _ => return Err(invalid().into()),
             }
-        });
+        }
 
         Ok(Self(map))
     }
@@ -97,19 +96,13 @@ impl Solution for Day {
 
         self.find_happiest_arrangement(start, start, &mut people, 0, &mut max_happiness);
 
-        Ok(max_happiness.ok_or_else(|| miette::miette!("No happiness found"))?.to_string())
+        Ok(max_happiness.ok_or_else(|| SolutionError::unsolvable("no happiness found"))?.to_string())
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-13.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 13))
+        .title(PuzzleId::aoc(2015, 13), "Knights of the Dinner Table");
 }
 
 #[cfg(test)]
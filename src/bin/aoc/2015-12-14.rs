@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
-use aoc_ornaments::{Part, ArgSolution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, ArgSolution, DefaultArgs, SolutionError};
 
 #[derive(Debug)]
 struct ReindeerStats {
@@ -15,12 +15,12 @@ struct ReindeerStats {
 }
 
 impl ReindeerStats {
-    pub fn new(speed: &str, time: &str, rest: &str) -> Self {
-        Self {
-            speed: speed.parse().unwrap(),
-            time: time.parse().unwrap(),
-            rest: rest.parse().unwrap(),
-        }
+    pub fn new(speed: &str, time: &str, rest: &str) -> Option<Self> {
+        Some(Self {
+            speed: speed.parse().ok()?,
+            time: time.parse().ok()?,
+            rest: rest.parse().ok()?,
+        })
     }
 }
 
@@ -41,16 +41,19 @@ impl FromStr for Day {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut map = HashMap::new();
 
-        input.lines().for_each(|line| {
+        for (n, line) in input.lines().enumerate() {
             let parts: Vec<&str> = line.split_whitespace().collect();
 
-            match parts.as_slice() {
+            let stats = match parts.as_slice() {
                 [name, "can", "fly", speed, "km/s", "for", time, "seconds,", "but", "then", "must", "rest", "for", rest, "seconds."] => {
-                    map.insert(name.to_string(), ReindeerStats::new(speed, time, rest));
+                    ReindeerStats::new(speed, time, rest).map(|stats| (name.to_string(), stats))
                 },
-                _ => panic!("Invalid input"),
-            }
-        });
+                _ => None,
+            };
+            let (name, stats) = stats.ok_or_else(|| SolutionError::line(input, n, "expected a reindeer's stats"))?;
+
+            map.insert(name, stats);
+        }
         
         Ok(Self(map))
     }
@@ -110,15 +113,20 @@ impl ArgSolution<u64> for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-14.txt"))?;
-    let part1 = day.solve(Part::One, 2503)?;
-    let part2 = day.solve(Part::Two, 2503)?;
+impl DefaultArgs<u64> for Day {
+    /// seconds of racing
+    fn args(_part: Part) -> u64 {
+        2503
+    }
 
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    fn example_args(_part: Part) -> u64 {
+        1000
+    }
+}
 
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, u64>(PuzzleId::aoc(2015, 14))
+        .title(PuzzleId::aoc(2015, 14), "Reindeer Olympics");
 }
 
 #[cfg(test)]
@@ -127,6 +135,11 @@ mod tests {
 
     use rstest::rstest;
 
+    aoc_ornaments::examples!(Day, u64 {
+        distance: Part::One, "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\nDancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds." => 1120,
+        points: Part::Two, "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\nDancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds." => 689,
+    });
+
     #[rstest]
     #[case(1, (14, 16))]
     #[case(10, (140, 160))]
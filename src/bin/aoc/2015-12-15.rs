@@ -2,11 +2,13 @@
 
 use std::{collections::HashMap, ops::Add, str::FromStr};
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution, SolutionError};
 use itertools::Itertools;
 use nom::{bytes::complete::{tag, take_until}, character::complete::{i32, space0, space1}, multi::separated_list1, sequence::{delimited, preceded, terminated, tuple}, IResult};
 
 type Ingredients = HashMap<String, Properties>;
+/// a name and its value, e.g. `capacity 2`
+type Property<'a> = (&'a str, i32);
 
 #[derive(Debug, Clone, Copy)]
 struct Properties {
@@ -21,9 +23,9 @@ impl FromStr for Properties {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let (_, props) = Day::parse_properties(input).map_err(|e| miette::miette!("Failed to parse properties: {}", e))?;
+        let (_, props) = Day::parse_properties(input).map_err(|e| aoc_ornaments::nom::error(input, e))?;
 
-        Ok(props.into())
+        Ok(Self::try_from(props).map_err(|e| SolutionError::line(input, 0, e))?)
     }
 }
 
@@ -108,11 +110,13 @@ impl Properties {
     }
 }
 
-impl From<Vec<(&str, i32)>> for Properties {
-    fn from(props: Vec<(&str, i32)>) -> Self {
+impl TryFrom<Vec<(&str, i32)>> for Properties {
+    type Error = String;
+
+    fn try_from(props: Vec<(&str, i32)>) -> Result<Self, Self::Error> {
         // We can use try_into to convert the Vec to an array
         let arr: [(&str, i32); 5] = props.try_into()
-            .expect("Expected exactly 5 properties");
+            .map_err(|_| "expected exactly 5 properties".to_string())?;
             
         // Now we need to arrange the values in the correct order
         // Since the property names could come in any order, we should match on them
@@ -129,17 +133,17 @@ impl From<Vec<(&str, i32)>> for Properties {
                 "flavor" => flavor = value,
                 "texture" => texture = value,
                 "calories" => calories = value,
-                _ => panic!("Unknown property: {}", name),
+                _ => return Err(format!("unknown property `{name}`")),
             }
         }
 
-        Self {
+        Ok(Self {
             capacity,
             durability,
             flavor,
             texture,
             calories,
-        }
+        })
     }
 }
 
@@ -150,18 +154,22 @@ impl FromStr for Day {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        Ok(Self(input.lines().fold(Ingredients::new(), |mut map, line| {
-            let (_, (name, props)) = Self::parse_ingredient(line).expect("valid input");
+        let mut map = Ingredients::new();
+
+        for (n, line) in input.lines().enumerate() {
+            let (_, (name, props)) = Self::parse_ingredient(line)
+                .map_err(|_| SolutionError::line(input, n, "expected `Name: capacity N, durability N, flavor N, texture N, calories N`"))?;
+            let props = Properties::try_from(props).map_err(|e| SolutionError::line(input, n, e))?;
 
             map.insert(name, props);
+        }
 
-            map
-        })))
+        Ok(Self(map))
     }
 }
 
 impl Day {
-    fn parse_ingredient(input: &str) -> IResult<&str, (String, Properties)> {
+    fn parse_ingredient(input: &str) -> IResult<&str, (String, Vec<Property<'_>>)> {
         let (list, name) = terminated(
             // This will capture everything until the colon
             take_until(":"),
@@ -171,7 +179,7 @@ impl Day {
 
         let (remainder, props) = Self::parse_properties(list)?;
 
-        Ok((remainder, (name.to_string(), Properties::from(props))))
+        Ok((remainder, (name.to_string(), props)))
     }
 
     fn parse_properties(input: &str) -> IResult<&str, Vec<(&str, i32)>> {
@@ -265,21 +273,17 @@ impl Solution for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-15.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 15))
+        .title(PuzzleId::aoc(2015, 15), "Science for Hungry People");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use aoc_ornaments::Part;
+
     use rstest::rstest;
 
     #[rstest]
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution};
 use nom::{bytes::complete::{tag, take_until}, character::complete::{space0, space1, u32}, multi::separated_list1, sequence::{delimited, preceded, tuple}, IResult};
 
 #[derive(Debug, PartialEq, Eq)]
@@ -297,13 +297,7 @@ perfumes: 1").expect("ok");
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-16.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
-}
\ No newline at end of file
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 16))
+        .title(PuzzleId::aoc(2015, 16), "Aunt Sue");
+}
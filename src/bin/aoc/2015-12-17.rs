@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, ArgSolution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, ArgSolution, DefaultArgs, SolutionError};
 use itertools::Itertools;
 
 #[derive(Debug, derive_more::Deref)]
@@ -12,7 +12,12 @@ impl FromStr for Day {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        Ok(Self(input.lines().map(|line| line.parse().unwrap()).collect()))
+        let containers = input.lines()
+            .enumerate()
+            .map(|(n, line)| line.parse().map_err(|_| SolutionError::line(input, n, "expected a container size")))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self(containers))
     }
 }
 
@@ -45,30 +50,31 @@ impl ArgSolution<u32> for Day {
         if let Some(&(_, count)) = solutions.first() {
             Ok(count)
         } else {
-            Err(miette::miette!("no combinations found"))
+            Err(SolutionError::unsolvable("no combinations found").into())
         }
         
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-17.txt"))?;
-    let part1 = day.solve(Part::One, 150)?;
-    let part2 = day.solve(Part::Two, 150)?;
+impl DefaultArgs<u32> for Day {
+    /// liters of eggnog
+    fn args(_part: Part) -> u32 {
+        150
+    }
 
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    fn example_args(_part: Part) -> u32 {
+        25
+    }
+}
 
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, u32>(PuzzleId::aoc(2015, 17))
+        .title(PuzzleId::aoc(2015, 17), "No Such Thing as Too Much");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn part1() {
-        let mut day = Day::from_str("20\n15\n10\n5\n5").unwrap();
-        assert_eq!(day.solve(Part::One, 25).unwrap(), "4");
-    }
-}
\ No newline at end of file
+    aoc_ornaments::examples!(Day, u32, "src/bin/aoc/2015-12-17.examples");
+}
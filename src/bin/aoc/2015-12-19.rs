@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
-use aoc_ornaments::{graph::Uniqued, Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, graph::Uniqued, Solution};
 use nom::{bytes::complete::{tag, take_while1}, character::complete::{alpha1, multispace0}, multi::separated_list1, sequence::{preceded, tuple}, IResult};
 
 type Molecule = String;
@@ -19,32 +19,17 @@ impl std::ops::Deref for Day {
     }
 }
 
-impl From<(Replacements, Molecule)> for Day {
-    fn from(replacements: (Replacements, Molecule)) -> Self {
+impl From<(Vec<(&str, &str, &str)>, &str)> for Day {
+    fn from(replacements: (Vec<(&str, &str, &str)>, &str)) -> Self {
         let mut map = HashMap::new();
 
-        replacements.0.iter().for_each(|replacement| {
-            let parts: Vec<&str> = replacement.split_whitespace().collect();
-
-            match parts.as_slice() {
-                [from, "=>", to] => {
-                    map.entry(from.to_string())
-                        .or_insert_with(Vec::new)
-                        .push(to.to_string());
-                }
-                _ => panic!("Invalid input"),
-            }
+        replacements.0.iter().for_each(|(from, _, to)| {
+            map.entry(from.to_string())
+                .or_insert_with(Vec::new)
+                .push(to.to_string());
         });
 
-        Self(map, replacements.1)
-    }
-}
-
-impl From<(Vec<(&str, &str, &str)>, &str)> for Day {
-    fn from(replacements: (Vec<(&str, &str, &str)>, &str)) -> Self {
-        Self::from((replacements.0.iter().map(|(from, _, to)| {
-            format!("{} => {}", from, to)
-        }).collect::<Vec<_>>(), replacements.1.to_string()))
+        Self(map, replacements.1.to_string())
     }
 }
 
@@ -52,11 +37,12 @@ impl FromStr for Day {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let (target_molecule, molecules) = Self::parse_molecules(input).expect("invalid input");
+        let (target_molecule, molecules) = Self::parse_molecules(input)
+            .map_err(|e| aoc_ornaments::nom::error(input, e))?;
         let (_, target_molecule) = preceded(
             multispace0::<&str, nom::error::Error<&str>>,
             take_while1(|c: char| c.is_ascii_alphabetic()),
-        )(target_molecule).expect("ok");
+        )(target_molecule).map_err(|e| aoc_ornaments::nom::error(input, e))?;
 
         Ok(Self::from((molecules, target_molecule)))
     }
@@ -144,22 +130,17 @@ impl Solution for Day {
     }
 }
 
-/// Run Part 1 and Part 2.
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-19.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 19))
+        .title(PuzzleId::aoc(2015, 19), "Medicine for Rudolph");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use aoc_ornaments::Part;
+
     use rstest::rstest;
 
     #[rstest]
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, ArgSolution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Part, ArgSolution, DefaultArgs};
 
 #[derive(Debug, derive_more::Deref, derive_more::DerefMut, Clone, Copy)]
 struct Day(usize);
@@ -66,13 +66,17 @@ impl ArgSolution<usize> for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-20.txt"))?;
-    let part1 = day.solve(Part::One, 10)?;
-    let part2 = day.solve(Part::Two, 11)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+impl DefaultArgs<usize> for Day {
+    /// presents each elf delivers per house, times their number
+    fn args(part: Part) -> usize {
+        match part {
+            Part::One => 10,
+            _ => 11,
+        }
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, usize>(PuzzleId::aoc(2015, 20))
+        .title(PuzzleId::aoc(2015, 20), "Infinite Elves and Infinite Houses");
+}
@@ -2,7 +2,7 @@
 
 use std::{str::FromStr, vec};
 
-use aoc_ornaments::{Part, ArgSolution, nom::split_newlines};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, ArgSolution, nom::split_newlines};
 use nom::{bytes::complete::{tag, take_until}, character::complete::{alpha1, char, digit1, multispace0, not_line_ending, space0, space1}, combinator::opt, multi::separated_list1, sequence::{preceded, terminated, tuple}, IResult};
 
 #[derive(Debug, Clone, Copy)]
@@ -313,8 +313,8 @@ impl ArgSolution<Stats> for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str("Weapons:    Cost  Damage  Armor
+/// The shop never changes, only the boss comes from the puzzle input
+const SHOP: &str = "Weapons:    Cost  Damage  Armor
 Dagger        8     4       0
 Shortsword   10     5       0
 Warhammer    25     6       0
@@ -334,14 +334,9 @@ Damage +2    50     2       0
 Damage +3   100     3       0
 Defense +1   20     0       1
 Defense +2   40     0       2
-Defense +3   80     0       3")?;
-    let boss = Boss::from_str(include_str!("../inputs/2015-12-21.txt")).expect("Failed to parse boss stats");
+Defense +3   80     0       3";
 
-    let part1 = day.solve(Part::One, boss)?;
-    let part2 = day.solve(Part::Two, boss)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
-}
\ No newline at end of file
+pub fn register(registry: &mut Registry) {
+    registry.input_arg_solution::<Day, Boss>(PuzzleId::aoc(2015, 21), SHOP)
+        .title(PuzzleId::aoc(2015, 21), "RPG Simulator 20XX");
+}
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, Solution};
+use aoc_ornaments::{cancel, puzzle::PuzzleId, runner::Registry, ArgSolution, SolutionError};
 use nom::{branch::alt, bytes::complete::{tag, take_until}, character::complete::{not_line_ending, space0, i32, u32}, combinator::{map, opt}, multi::separated_list1, sequence::{terminated, tuple}, IResult};
 use std::collections::{BinaryHeap, HashSet};
 use std::cmp::Ordering;
@@ -30,7 +30,7 @@ impl FromStr for Boss {
 
     fn from_str(input: &str) -> miette::Result<Self> {
         let (_, boss) = Self::parse_boss_stats(input)
-            .map_err(|e| miette::miette!(e.to_owned()))?;
+            .map_err(|e| aoc_ornaments::nom::error(input, e))?;
 
         Ok(boss)
     }
@@ -86,7 +86,7 @@ impl FromStr for Day {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let (_, spells) = Self::parse_spells(input).map_err(|e| miette::miette!(e.to_owned()))?;
+        let (_, spells) = Self::parse_spells(input).map_err(|e| aoc_ornaments::nom::error(input, e))?;
         
         Ok(Self(spells))
     }
@@ -306,17 +306,18 @@ enum GameMode {
 }
 
 impl Day {
-    fn find_least_mana(&self, mode: GameMode) -> Option<i32> {
+    fn find_least_mana(&self, boss: Boss, mode: GameMode) -> aoc_ornaments::SolutionResult<Option<i32>> {
         let mut heap = BinaryHeap::new();
         let mut seen = HashSet::new();
         let player = Player::default();    
-        let boss = Boss::from_str(include_str!("../inputs/2015-12-22.txt")).expect("Failed to parse boss stats");
         
         let initial = GameState::new(player, boss);
         heap.push(initial.clone());
         seen.insert(initial);
 
         while let Some(mut state) = heap.pop() {
+            cancel::checkpoint()?;
+
             if let GameMode::Hard = mode {
                 state.player.hp -= 1;
                 if state.player.hp <= 0 { continue; }
@@ -324,13 +325,13 @@ impl Day {
 
             // Boss is dead?
             if state.boss.hp <= 0 {
-                return Some(state.mana_spent);
+                return Ok(Some(state.mana_spent));
             }
 
             // Player's turn
             state.apply_effects();
             if state.boss.hp <= 0 {
-                return Some(state.mana_spent);
+                return Ok(Some(state.mana_spent));
             }
 
             // Try each spell
@@ -341,7 +342,7 @@ impl Day {
                 // Boss turn
                 new_state.apply_effects();
                 if new_state.boss.hp <= 0 {
-                    return Some(new_state.mana_spent);
+                    return Ok(Some(new_state.mana_spent));
                 }
 
                 // Boss attacks
@@ -355,36 +356,32 @@ impl Day {
             }
         }
 
-        None
+        Ok(None)
     }
 }
 
-impl Solution for Day {
+impl ArgSolution<Boss> for Day {
     type Output = i32;
 
-    fn part1(&mut self) -> aoc_ornaments::SolutionResult<Self::Output> {
-        Self::find_least_mana(&self, GameMode::Normal)
-            .ok_or_else(|| miette::miette!("No solution found"))
+    fn part1(&mut self, boss: Boss) -> aoc_ornaments::SolutionResult<Self::Output> {
+        Self::find_least_mana(&self, boss, GameMode::Normal)?
+            .ok_or_else(|| SolutionError::unsolvable("no way to beat the boss").into())
     }
 
-    fn part2(&mut self) -> aoc_ornaments::SolutionResult<Self::Output> {
-        Self::find_least_mana(&self, GameMode::Hard)
-            .ok_or_else(|| miette::miette!("No solution found"))
+    fn part2(&mut self, boss: Boss) -> aoc_ornaments::SolutionResult<Self::Output> {
+        Self::find_least_mana(&self, boss, GameMode::Hard)?
+            .ok_or_else(|| SolutionError::unsolvable("no way to beat the boss").into())
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str("Magic Missile costs 53 mana. It instantly does 4 damage.
+/// The spells never change, only the boss comes from the puzzle input
+const SPELLS: &str = "Magic Missile costs 53 mana. It instantly does 4 damage.
 Drain costs 73 mana. It instantly does 2 damage and heals you for 2 hit points.
 Shield costs 113 mana. It starts an effect that lasts for 6 turns. While it is active, your armor is increased by 7.
 Poison costs 173 mana. It starts an effect that lasts for 6 turns. At the start of each turn while it is active, it deals the boss 3 damage.
-Recharge costs 229 mana. It starts an effect that lasts for 5 turns. At the start of each turn while it is active, it gives you 101 new mana.")?;
-
-    let part1 = day.solve(Part::One)?;
-    let part2 = day.solve(Part::Two)?;
+Recharge costs 229 mana. It starts an effect that lasts for 5 turns. At the start of each turn while it is active, it gives you 101 new mana.";
 
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
-}
\ No newline at end of file
+pub fn register(registry: &mut Registry) {
+    registry.input_arg_solution::<Day, Boss>(PuzzleId::aoc(2015, 22), SPELLS)
+        .title(PuzzleId::aoc(2015, 22), "Wizard Simulator 20XX");
+}
@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, str::FromStr};
 
-use aoc_ornaments::{intcode::VirtualMachine, nom::split_newlines, Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, intcode::VirtualMachine, nom::split_newlines, Part, Solution};
 use nom::{branch::alt, bytes::complete::tag, character::complete::{alpha1, digit1, space0, space1}, sequence::tuple, IResult};
 
 #[derive(Debug, derive_more::Deref, derive_more::DerefMut)]
@@ -158,18 +158,9 @@ impl Solution for Day {
     }
 }
 
-/// Run Part 1 and Part 2. Reset the state after Part 1.
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-23.txt"))?;
-    let part1 = day.solve(Part::One)?;
-    // state is dirty after part1, need to reset
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-23.txt"))?;
-    let part2 = day.solve(Part::Two)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 23))
+        .title(PuzzleId::aoc(2015, 23), "Opening the Turing Lock");
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{Part, ArgSolution};
+use aoc_ornaments::{cancel, puzzle::PuzzleId, runner::Registry, Part, ArgSolution, DefaultArgs, SolutionError, SolutionResult};
 use itertools::Itertools;
 
 #[derive(Debug, derive_more::Deref)]
@@ -12,30 +12,45 @@ impl FromStr for Day {
     type Err = miette::Error;
 
     fn from_str(input: &str) -> miette::Result<Self> {
-        let packages = input.lines().map(|line| line.parse().unwrap()).collect();
+        let packages = input.lines()
+            .enumerate()
+            .map(|(n, line)| line.parse().map_err(|_| SolutionError::line(input, n, "expected a package weight")))
+            .collect::<Result<_, _>>()?;
 
         Ok(Self(packages))
     }
 }
 
+/// combinations between checks for a timeout
+const CHECK_EVERY: usize = 4096;
+
 impl Day {
-    fn quantum_entanglement(&self, compartments: usize) -> Result<usize, miette::Error> {
+    fn quantum_entanglement(&self, compartments: usize) -> SolutionResult<usize> {
         let total_weight = self.iter().sum::<usize>();
         let group_weight = total_weight / compartments;
     
         // Start with smallest possible combinations and return early
         for n in 1..self.len() {
-            if let Some(min_product) = self.iter()
-                .combinations(n)
-                .filter(|combo| combo.iter().copied().sum::<usize>() == group_weight)
-                .map(|combo| combo.iter().copied().product::<usize>())
-                .min()
-            {
+            let mut min_product = None;
+
+            for (i, combo) in self.iter().combinations(n).enumerate() {
+                // the number of combinations explodes on a bad input, but the clock doesn't need reading for each
+                if i % CHECK_EVERY == 0 {
+                    cancel::checkpoint()?;
+                }
+
+                if combo.iter().copied().sum::<usize>() == group_weight {
+                    let product = combo.iter().copied().product::<usize>();
+                    min_product = Some(min_product.map_or(product, |min: usize| min.min(product)));
+                }
+            }
+
+            if let Some(min_product) = min_product {
                 return Ok(min_product);
             }
         }
     
-        Err(miette::miette!("No valid combinations found"))
+        Err(SolutionError::unsolvable("no valid combinations found").into())
     }
 }
 
@@ -47,15 +62,19 @@ impl ArgSolution<usize> for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-24.txt"))?;
-    let part1 = day.solve(Part::One, 3)?;
-    let part2 = day.solve(Part::Two, 4)?;
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+impl DefaultArgs<usize> for Day {
+    /// groups to split the packages into, the trunk joins in part 2
+    fn args(part: Part) -> usize {
+        match part {
+            Part::One => 3,
+            _ => 4,
+        }
+    }
+}
 
-    Ok(())
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, usize>(PuzzleId::aoc(2015, 24))
+        .title(PuzzleId::aoc(2015, 24), "It Hangs in the Balance");
 }
 
 #[cfg(test)]
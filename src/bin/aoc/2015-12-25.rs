@@ -2,7 +2,7 @@
 
 use std::str::FromStr;
 
-use aoc_ornaments::{spatial::Position, Part, Solution};
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, spatial::Position, Part, Solution};
 use nom::{branch::alt, bytes::complete::{tag, take_until}, character::complete::{digit1, space0}, combinator::{map, map_res}, sequence::{preceded, tuple}, IResult};
 
 #[derive(Debug, derive_more::Deref)]
@@ -89,13 +89,10 @@ impl Solution for Day {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let mut day = Day::from_str(include_str!("../inputs/2015-12-25.txt"))?;
-    let part1 = day.solve(Part::One)?;
-
-    println!("Part 1: {}", part1);
-
-    Ok(())
+/// Only Part 1, the last day has no Part 2 to register
+pub fn register(registry: &mut Registry) {
+    registry.solution_part::<Day>(PuzzleId::aoc(2015, 25), Part::One)
+        .title(PuzzleId::aoc(2015, 25), "Let It Snow");
 }
 
 #[cfg(test)]
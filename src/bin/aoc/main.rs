@@ -0,0 +1,131 @@
+//! Run any registered solution
+//!
+//! ```sh
+//! cargo run                  # everything
+//! cargo run -- 2015          # a whole year
+//! cargo run -- 2015 4        # a single day
+//! cargo run -- 2015 4 2      # a single part
+//! ```
+//!
+//! Parts that aren't written yet are listed as pending rather than stopping the run.
+//! Start a new one with `cargo run -- new 2015 6`, see [aoc_ornaments::scaffold]. Its examples come from the saved puzzle page, if there is one.
+//!
+//! `--jobs N` runs parts on N threads, `-j 0` uses one per CPU. Output stays in order either way.
+//! `--timeout SECS` fails any part that goes over, see [aoc_ornaments::cancel].
+//!
+//! Inputs are searched for in `$AOC_INPUTS`, `inputs/`, `src/inputs/` then `examples/inputs/`,
+//! or given explicitly with `--input FILE`, `--input -` for stdin, or `--inputs DIR`.
+//!
+//! Parsing and each part are timed. To benchmark, repeat them and compare against an earlier run:
+//!
+//! ```sh
+//! cargo run --release -- 2015 --bench 10 --warmup 2 --save before.json
+//! cargo run --release -- 2015 --bench 10 --warmup 2 --baseline before.json
+//! ```
+//!
+//! Once answers are accepted, record them and check every later run against them:
+//!
+//! ```sh
+//! cargo run -- 2015 --answers answers.json --record
+//! cargo run -- --answers answers.json
+//! ```
+//!
+//! Answers turned down by hand can be noted, so a run warns before the same one, or one past a known bound, is tried again:
+//!
+//! ```sh
+//! cargo run -- reject 2015 4 1 300000 --too-high --rejected rejected.json
+//! cargo run -- 2015 4 --rejected rejected.json
+//! ```
+//!
+//! `--report FILE` writes every part's title, answer, timing and status as a markdown table, or JSON for a `.json` file:
+//!
+//! ```sh
+//! cargo run --release -- --answers answers.json --report results.md
+//! ```
+
+use aoc_ornaments::{rejected::Rejection, runner::{Args, Registry}, scaffold::Scaffold};
+
+#[path = "2015-12-02.rs"]
+mod day_2015_12_02;
+#[path = "2015-12-04.rs"]
+mod day_2015_12_04;
+#[path = "2015-12-05.rs"]
+mod day_2015_12_05;
+#[path = "2015-12-08.rs"]
+mod day_2015_12_08;
+#[path = "2015-12-10.rs"]
+mod day_2015_12_10;
+#[path = "2015-12-11.rs"]
+mod day_2015_12_11;
+#[path = "2015-12-12.rs"]
+mod day_2015_12_12;
+#[path = "2015-12-13.rs"]
+mod day_2015_12_13;
+#[path = "2015-12-14.rs"]
+mod day_2015_12_14;
+#[path = "2015-12-15.rs"]
+mod day_2015_12_15;
+#[path = "2015-12-16.rs"]
+mod day_2015_12_16;
+#[path = "2015-12-17.rs"]
+mod day_2015_12_17;
+#[path = "2015-12-19.rs"]
+mod day_2015_12_19;
+#[path = "2015-12-20.rs"]
+mod day_2015_12_20;
+#[path = "2015-12-21.rs"]
+mod day_2015_12_21;
+#[path = "2015-12-22.rs"]
+mod day_2015_12_22;
+#[path = "2015-12-23.rs"]
+mod day_2015_12_23;
+#[path = "2015-12-24.rs"]
+mod day_2015_12_24;
+#[path = "2015-12-25.rs"]
+mod day_2015_12_25;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    day_2015_12_02::register(&mut registry);
+    day_2015_12_04::register(&mut registry);
+    day_2015_12_05::register(&mut registry);
+    day_2015_12_08::register(&mut registry);
+    day_2015_12_10::register(&mut registry);
+    day_2015_12_11::register(&mut registry);
+    day_2015_12_12::register(&mut registry);
+    day_2015_12_13::register(&mut registry);
+    day_2015_12_14::register(&mut registry);
+    day_2015_12_15::register(&mut registry);
+    day_2015_12_16::register(&mut registry);
+    day_2015_12_17::register(&mut registry);
+    day_2015_12_19::register(&mut registry);
+    day_2015_12_20::register(&mut registry);
+    day_2015_12_21::register(&mut registry);
+    day_2015_12_22::register(&mut registry);
+    day_2015_12_23::register(&mut registry);
+    day_2015_12_24::register(&mut registry);
+    day_2015_12_25::register(&mut registry);
+
+    registry
+}
+
+fn main() -> miette::Result<()> {
+    let mut args = std::env::args().skip(1).peekable();
+
+    if args.next_if_eq("new").is_some() {
+        for path in Scaffold::from_args(args)?.create(".")? {
+            println!("created {}", path.display());
+        }
+
+        return Ok(());
+    }
+
+    if args.next_if_eq("reject").is_some() {
+        return Rejection::from_args(args)?.save();
+    }
+
+    let args = Args::parse(args)?;
+
+    args.runner(registry())?.run(&args.selection)
+}
@@ -1,9 +1,11 @@
 use std::{collections::BTreeMap, str::FromStr};
 
+use crate::SolutionError;
+
 pub type Wires<T> = BTreeMap<String, T>;
 pub type Instructions<O> = Vec<LogicGate<O>>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LogicCircuit<T, O: Clone> {
     pub wires: BTreeMap<String, T>,
     instructions: Vec<LogicGate<O>>,
@@ -20,16 +22,16 @@ pub enum Operand {
 }
 
 impl FromStr for Operand {
-    type Err = miette::Error;
+    type Err = SolutionError;
 
-    fn from_str(s: &str) -> miette::Result<Self> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "AND" => Ok(Self::And),
             "RSHIFT" => Ok(Self::RightShift),
             "OR" => Ok(Self::Or),
             "LSHIFT" => Ok(Self::LeftShift),
             "NOT" => Ok(Self::Not),
-            _ => Err(miette::miette!("Invalid operand: {s}")),
+            _ => Err(SolutionError::parse(s, 0..s.len(), "invalid operand")),
         }
     }
 }
@@ -72,7 +74,7 @@ where
 }
 
 impl LogicCircuit<String, Operand> {
-    pub fn execute(&mut self) -> miette::Result<()> {
+    pub fn execute(&mut self) -> Result<(), SolutionError> {
         let mut pending = self.instructions.clone();
         let mut progress = true;
 
@@ -130,9 +132,9 @@ impl LogicCircuit<String, Operand> {
         Ok(())
     }
 
-    pub fn resolve_wire(&self, wire: &str) -> miette::Result<u16> {
+    pub fn resolve_wire(&self, wire: &str) -> Result<u16, SolutionError> {
         self.get_wire_value(wire)
-            .ok_or_else(|| miette::miette!("Unable to resolve wire: {wire}"))
+            .ok_or_else(|| SolutionError::unsolvable(format!("unable to resolve wire {wire}")))
     }
 }
 
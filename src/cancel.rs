@@ -0,0 +1,137 @@
+//! Time budgets for parts that might run away, checked cooperatively
+//!
+//! With `--timeout SECS` the runner gives each part a [Token] with that budget, afresh for each `--bench` run. Long searches call [checkpoint] as they go,
+//! which fails with [SolutionError::TimedOut] once it's spent:
+//!
+//! ```ignore
+//! while let Some(state) = queue.pop() {
+//!     aoc_ornaments::cancel::checkpoint()?;
+//!     // ...
+//! }
+//! ```
+//!
+//! Nothing is interrupted, a part that never checks runs to the end, but it's still reported as timed out.
+//! Helpers that return an [Option] rather than a result can give up early on [is_cancelled] instead.
+//!
+//! The token belongs to the thread running the part, take it along to any others: `let token = cancel::current();`
+//! then `token.scope(|| ...)` on the new thread.
+
+use std::{cell::RefCell, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::{Duration, Instant}};
+
+use crate::{SolutionError, SolutionResult};
+
+thread_local! {
+    static CURRENT: RefCell<Token> = RefCell::default();
+}
+
+/// A deadline and a flag, shared by every clone
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    /// when it runs out, and how long it was
+    deadline: Option<(Instant, Duration)>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Token {
+    /// Only cancelled by hand
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs out `budget` from now
+    pub fn with_budget(budget: Duration) -> Self {
+        Self { deadline: Some((Instant::now() + budget, budget)), ..Self::default() }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.check().is_err()
+    }
+
+    /// Reads the clock, so call it per step of a search rather than in the innermost loop
+    pub fn check(&self) -> Result<(), SolutionError> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(SolutionError::Cancelled);
+        }
+
+        match self.deadline {
+            Some((deadline, budget)) if Instant::now() >= deadline => Err(SolutionError::TimedOut(budget)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Run `f` with this as the [current] token on this thread
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// puts the previous token back, even on a panic
+        struct Restore(Option<Token>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(previous) = self.0.take() {
+                    CURRENT.set(previous);
+                }
+            }
+        }
+
+        let _restore = Restore(Some(CURRENT.replace(self.clone())));
+
+        f()
+    }
+}
+
+/// This thread's token, one that's never cancelled outside of a [Token::scope]
+pub fn current() -> Token {
+    CURRENT.with_borrow(Token::clone)
+}
+
+/// Fails once the current part is out of time or cancelled
+pub fn checkpoint() -> SolutionResult<()> {
+    CURRENT.with_borrow(Token::check).map_err(Into::into)
+}
+
+pub fn is_cancelled() -> bool {
+    CURRENT.with_borrow(Token::is_cancelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget() {
+        let token = Token::with_budget(Duration::from_millis(20));
+        assert!(token.check().is_ok());
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(token.check(), Err(SolutionError::TimedOut(Duration::from_millis(20))));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = Token::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+
+        token.cancel();
+        assert_eq!(clone.check(), Err(SolutionError::Cancelled));
+    }
+
+    #[test]
+    fn test_scope() {
+        let token = Token::new();
+        token.cancel();
+
+        assert!(checkpoint().is_ok());
+        assert!(token.scope(|| checkpoint().is_err() && is_cancelled()));
+        // restored afterwards, even if the scope panics
+        let _ = std::panic::catch_unwind(|| token.scope(|| panic!("boom")));
+        assert!(checkpoint().is_ok());
+
+        // taken along to another thread
+        let taken = token.scope(current);
+        assert!(std::thread::spawn(move || taken.scope(is_cancelled)).join().unwrap());
+    }
+}
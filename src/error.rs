@@ -0,0 +1,151 @@
+//! Errors a solution can run into, beyond whatever its own parsing library reports
+//!
+//! Parse errors keep the puzzle input and a byte span into it, so miette can point at the line that broke:
+//!
+//! ```ignore
+//! for (n, line) in input.lines().enumerate() {
+//!     let value = line.parse().map_err(|_| SolutionError::line(input, n, "expected a number"))?;
+//! }
+//! ```
+
+use std::{ops::Range, time::Duration};
+
+use thiserror::Error;
+
+use crate::{Part, SolutionReport};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "miette", derive(miette::Diagnostic))]
+pub enum SolutionError {
+    #[error("Failed to parse input: {message}")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::parse)))]
+    Parse {
+        message: String,
+        #[cfg_attr(feature = "miette", source_code)]
+        input: String,
+        /// bytes into `input`
+        #[cfg_attr(feature = "miette", label("{message}"))]
+        span: Range<usize>,
+    },
+
+    /// the input parsed, but has no answer
+    #[error("No solution: {0}")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::unsolvable)))]
+    Unsolvable(String),
+
+    #[error("{0} isn't implemented yet")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::unimplemented)))]
+    Unimplemented(Part),
+
+    #[error("Invalid argument `{arg}`: {message}")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::argument)))]
+    InvalidArgument { arg: String, message: String },
+
+    /// out of its time budget, see [cancel](crate::cancel)
+    #[error("Timed out after {0:?}")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::timeout), help("raise the budget with --timeout SECS, or look for a search that never ends")))]
+    TimedOut(Duration),
+
+    #[error("Cancelled")]
+    #[cfg_attr(feature = "miette", diagnostic(code(solution::cancelled)))]
+    Cancelled,
+}
+
+impl SolutionError {
+    pub fn parse(input: &str, span: Range<usize>, message: impl ToString) -> Self {
+        Self::Parse { message: message.to_string(), input: input.to_string(), span }
+    }
+
+    /// Points at the `n`th line (zero based, as from `enumerate()`), or the end of the input if there aren't that many
+    pub fn line(input: &str, n: usize, message: impl ToString) -> Self {
+        let start: usize = input.split_inclusive('\n').take(n).map(str::len).sum();
+        let len = input[start..].lines().next().map_or(0, str::len);
+
+        Self::parse(input, start..start + len, message)
+    }
+
+    /// Points at where `rest` starts, as left over by a parser like nom
+    ///
+    /// A `rest` that isn't a slice of `input` points nowhere, at `0..0`, rather than somewhere wrong.
+    pub fn at(input: &str, rest: &str, message: impl ToString) -> Self {
+        let start = (rest.as_ptr() as usize).checked_sub(input.as_ptr() as usize)
+            .filter(|start| start + rest.len() <= input.len());
+        let Some(start) = start else {
+            return Self::parse(input, 0..0, message);
+        };
+        let len = rest.lines().next().map_or(0, str::len);
+
+        Self::parse(input, start..start + len, message)
+    }
+
+    pub fn unsolvable(message: impl ToString) -> Self {
+        Self::Unsolvable(message.to_string())
+    }
+
+    pub fn invalid_argument(arg: impl ToString, message: impl ToString) -> Self {
+        Self::InvalidArgument { arg: arg.to_string(), message: message.to_string() }
+    }
+
+    /// Whether a report is only a part that hasn't been written yet, rather than a real failure
+    pub fn is_unimplemented(report: &SolutionReport) -> bool {
+        matches!(report.downcast_ref::<SolutionError>(), Some(SolutionError::Unimplemented(_)))
+    }
+}
+
+/// Whether a panic came from `todo!()`
+///
+/// `unimplemented!()` isn't included, it's used for invalid input too often to mean "pending"
+pub fn is_todo(payload: &(dyn std::any::Any + Send)) -> bool {
+    let message = payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str));
+
+    message.is_some_and(|message| message.starts_with("not yet implemented"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(error: SolutionError) -> Range<usize> {
+        match error {
+            SolutionError::Parse { span, .. } => span,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_spans() {
+        let input = "1\n22\nx\n4";
+
+        assert_eq!(span(SolutionError::line(input, 0, "")), 0..1);
+        assert_eq!(span(SolutionError::line(input, 2, "")), 5..6);
+        assert_eq!(span(SolutionError::line(input, 9, "")), 8..8);
+        assert_eq!(span(SolutionError::at(input, &input[5..], "")), 5..6);
+        assert_eq!(span(SolutionError::at(input, &input[input.len()..], "")), 8..8);
+        assert_eq!(span(SolutionError::at(input, "a much longer string from elsewhere", "")), 0..0);
+    }
+
+    #[cfg(feature = "miette")]
+    #[test]
+    fn test_diagnostic() {
+        use miette::Diagnostic;
+
+        let error = SolutionError::line("1\n22\nx\n4", 2, "expected a number");
+        let label = error.labels().unwrap().next().unwrap();
+
+        assert_eq!(label.label(), Some("expected a number"));
+        assert_eq!((label.offset(), label.len()), (5, 1));
+        assert!(error.source_code().is_some());
+    }
+
+    #[test]
+    fn test_is_todo() {
+        let todo = std::panic::catch_unwind(|| todo!()).unwrap_err();
+        let todo_why = std::panic::catch_unwind(|| todo!("part {}", 2)).unwrap_err();
+        let invalid = std::panic::catch_unwind(|| unimplemented!("invalid input")).unwrap_err();
+
+        assert!(is_todo(&*todo));
+        assert!(is_todo(&*todo_why));
+        assert!(!is_todo(&*invalid));
+    }
+}
@@ -0,0 +1,431 @@
+//! Examples from the puzzle text, as tables instead of hand-written tests
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod tests {
+//!     use super::*;
+//!
+//!     aoc_ornaments::examples!(Day {
+//!         shortest: Part::One, "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141" => 605,
+//!         longest: Part::Two, "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141" => 982,
+//!     });
+//! }
+//! ```
+//!
+//! [ArgSolution]s list the argument's type, then the argument before each answer:
+//!
+//! ```ignore
+//! aoc_ornaments::examples!(Day, u32 {
+//!     liters: Part::One, "20\n15\n10\n5\n5", 25 => 4,
+//! });
+//! ```
+//!
+//! or leave the argument out when the solution implements [DefaultArgs](crate::DefaultArgs), to use its `example_args`:
+//!
+//! ```ignore
+//! aoc_ornaments::examples!(Day, u32 {
+//!     liters: Part::One, "20\n15\n10\n5\n5" => 4,
+//! });
+//! ```
+//!
+//! or the cases can live in a sidecar file, relative to the crate root:
+//!
+//! ```ignore
+//! aoc_ornaments::examples!(Day, "src/bin/aoc/2015-12-17.examples");
+//! aoc_ornaments::examples!(Day, u32, "src/bin/aoc/2015-12-17.examples");
+//! ```
+//!
+//! everybody.codes [Notes](crate::Notes) are parsed per part: `aoc_ornaments::examples!(notes Day, "src/bin/aoc/everybody_codes_e2024_q01.examples");`
+//!
+//! each case in a sidecar file starts with a header, `=== part N [with ARG] -> ANSWER`, followed by its input.
+//! With an argument type, the solution implements [DefaultArgs](crate::DefaultArgs) and a header without `with` uses its `example_args`:
+//!
+//! ```text
+//! === part 1 with 25 -> 4
+//! 20
+//! 15
+//! 10
+//! 5
+//! 5
+//! ```
+//!
+//! Neither the blank line between cases nor the input's last line ending are part of it, everything else is kept as written.
+//!
+//! Both forms expand into [rstest](https://docs.rs/rstest) cases, one per example, so the calling crate needs `rstest` as a dev-dependency.
+//! A sidecar's cases are named after their part, `case_2_part1` is the file's second example, for part 1.
+//! Both forms define a test named `examples`, so only use one per module.
+
+use std::{path::Path, str::FromStr};
+
+use crate::{ArgSolution, DefaultArgs, Notes, Parse, Part, Solution, SolutionReport, SolutionResult};
+
+#[doc(hidden)]
+pub use aoc_ornaments_macros::sidecar_cases;
+
+/// A known answer from the puzzle text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example<A = ()> {
+    pub part: Part,
+    pub input: String,
+    /// only used by [ArgSolution]s
+    pub args: A,
+    pub expected: String,
+}
+
+impl Example {
+    pub fn new(part: Part, input: &str, expected: impl ToString) -> Self {
+        Self::with_args(part, input, (), expected)
+    }
+
+    /// Parse and solve the example
+    pub fn solve<'a, D>(&'a self) -> SolutionResult<String>
+    where
+        D: Solution + Parse<'a>,
+        <D as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        D::solve_fresh(&self.input, self.part)
+    }
+
+    pub fn assert<'a, D>(&'a self)
+    where
+        D: Solution + Parse<'a>,
+        <D as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        match self.solve::<D>() {
+            Ok(answer) => assert_eq!(answer, self.expected, "{} of\n{}", self.part, self.input),
+            Err(e) => panic!("{} of\n{}\nfailed: {e:?}", self.part, self.input),
+        }
+    }
+
+    /// Parse the example as its part's own notes, for everybody.codes
+    pub fn solve_notes<'a, D>(&'a self) -> SolutionResult<String>
+    where
+        D: Solution + Notes<'a>,
+    {
+        D::solve_notes(&self.input, self.part)
+    }
+
+    pub fn assert_notes<'a, D>(&'a self)
+    where
+        D: Solution + Notes<'a>,
+    {
+        match self.solve_notes::<D>() {
+            Ok(answer) => assert_eq!(answer, self.expected, "{} of\n{}", self.part, self.input),
+            Err(e) => panic!("{} of\n{}\nfailed: {e:?}", self.part, self.input),
+        }
+    }
+
+    /// Every case in a sidecar file, none of which may have an argument
+    pub fn load(path: impl AsRef<Path>) -> SolutionResult<Vec<Self>> {
+        parse_sidecar(path.as_ref())?.into_iter()
+            .map(|(example, args)| match args {
+                None => Ok(Example::with_args(example.part, &example.input, (), example.expected)),
+                Some(args) => Err(crate::report(format!("Unexpected argument `{args}` for {}", example.part))),
+            })
+            .collect()
+    }
+
+    /// The cases as a sidecar file, the other way to [load](Self::load)
+    pub fn sidecar(examples: &[Self]) -> String {
+        examples.iter()
+            .map(|example| format!("=== part {} -> {}\n{}\n", example.part.number(), example.expected, example.input))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<A: Clone> Example<A> {
+    pub fn with_args(part: Part, input: &str, args: A, expected: impl ToString) -> Self {
+        Self { part, input: input.to_string(), args, expected: expected.to_string() }
+    }
+
+    /// Parse and solve the example with its argument
+    pub fn solve_with<'a, D>(&'a self) -> SolutionResult<String>
+    where
+        D: ArgSolution<A> + Parse<'a>,
+        <D as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        D::solve_fresh(&self.input, self.part, self.args.clone())
+    }
+
+    pub fn assert_with<'a, D>(&'a self)
+    where
+        D: ArgSolution<A> + Parse<'a>,
+        <D as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        match self.solve_with::<D>() {
+            Ok(answer) => assert_eq!(answer, self.expected, "{} of\n{}", self.part, self.input),
+            Err(e) => panic!("{} of\n{}\nfailed: {e:?}", self.part, self.input),
+        }
+    }
+}
+
+impl<A: Clone> Example<A> {
+    /// With the argument the solution declares for examples
+    pub fn with_default_args<D: DefaultArgs<A>>(part: Part, input: &str, expected: impl ToString) -> Self {
+        Self::with_args(part, input, D::example_args(part), expected)
+    }
+}
+
+impl<A: Clone + FromStr> Example<A> {
+    /// Every case in a sidecar file, all of which must have an argument
+    pub fn load_with(path: impl AsRef<Path>) -> SolutionResult<Vec<Self>> {
+        Self::load_or(path.as_ref(), |_| None)
+    }
+
+    /// Every case in a sidecar file, with the solution's [example_args](DefaultArgs::example_args) for any that leave the argument out
+    pub fn load_with_defaults<D: DefaultArgs<A>>(path: impl AsRef<Path>) -> SolutionResult<Vec<Self>> {
+        Self::load_or(path.as_ref(), |part| Some(D::example_args(part)))
+    }
+
+    fn load_or(path: &Path, default: impl Fn(Part) -> Option<A>) -> SolutionResult<Vec<Self>> {
+        parse_sidecar(path)?.into_iter()
+            .map(|(example, args)| {
+                let args = match args {
+                    Some(args) => args.parse().map_err(|_| crate::report(format!("Invalid argument `{args}` for {}", example.part)))?,
+                    None => default(example.part).ok_or_else(|| crate::report(format!("Missing argument for {}", example.part)))?,
+                };
+
+                Ok(Example::with_args(example.part, &example.input, args, example.expected))
+            })
+            .collect()
+    }
+}
+
+/// `=== part N [with ARG] -> ANSWER`
+fn parse_header(line: &str) -> Option<(Part, Option<String>, String)> {
+    let header = line.strip_prefix("=== part ")?;
+    let (lhs, expected) = header.split_once(" -> ")?;
+    let (part, args) = match lhs.split_once(" with ") {
+        Some((part, args)) => (part, Some(args.trim().to_string())),
+        None => (lhs, None),
+    };
+
+    Some((part.parse().ok()?, args, expected.trim().to_string()))
+}
+
+fn parse_sidecar(path: &Path) -> SolutionResult<Vec<(Example, Option<String>)>> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| crate::report(format!("Failed to read {}: {e}", path.display())))?;
+
+    let mut examples: Vec<(Example, Option<String>)> = Vec::new();
+    // each case's input, line by line with the line endings left on
+    let mut inputs: Vec<Vec<&str>> = Vec::new();
+
+    for (n, line) in text.split_inclusive('\n').enumerate() {
+        let header = line.trim_end_matches(['\r', '\n']);
+
+        if header.starts_with("=== ") {
+            let (part, args, expected) = parse_header(header)
+                .ok_or_else(|| crate::report(format!("{}:{}: invalid header `{header}`", path.display(), n + 1)))?;
+
+            examples.push((Example::new(part, "", expected), args));
+            inputs.push(Vec::new());
+        } else if let Some(input) = inputs.last_mut() {
+            input.push(line);
+        } else if !header.trim().is_empty() {
+            return Err(crate::report(format!("{}:{}: input before the first header", path.display(), n + 1)));
+        }
+    }
+
+    let last = inputs.len().saturating_sub(1);
+
+    for (i, ((example, _), mut input)) in examples.iter_mut().zip(inputs).enumerate() {
+        // a blank line separates cases, anything else is the input exactly as written
+        if i < last && input.last().is_some_and(|line| matches!(*line, "\n" | "\r\n")) {
+            input.pop();
+        }
+
+        let input = input.concat();
+        let input = input.strip_suffix('\n').map_or(input.as_str(), |input| input.strip_suffix('\r').unwrap_or(input));
+        example.input = input.to_string();
+    }
+
+    Ok(examples)
+}
+
+/// Expands a table of examples into test cases, see the [module docs](crate::example)
+#[macro_export]
+macro_rules! examples {
+    (notes $day:ty, $file:literal) => {
+        #[$crate::example::sidecar_cases($file)]
+        #[::rstest::rstest]
+        fn examples(#[case] n: usize) {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $file);
+
+            $crate::example::Example::load(path).unwrap()[n].assert_notes::<$day>();
+        }
+    };
+    ($day:ty, $file:literal) => {
+        #[$crate::example::sidecar_cases($file)]
+        #[::rstest::rstest]
+        fn examples(#[case] n: usize) {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $file);
+
+            $crate::example::Example::load(path).unwrap()[n].assert::<$day>();
+        }
+    };
+    ($day:ty, $args:ty, $file:literal) => {
+        #[$crate::example::sidecar_cases($file)]
+        #[::rstest::rstest]
+        fn examples(#[case] n: usize) {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $file);
+
+            $crate::example::Example::<$args>::load_with_defaults::<$day>(path).unwrap()[n].assert_with::<$day>();
+        }
+    };
+    ($day:ty { $($name:ident: $part:expr, $input:expr => $expected:expr),+ $(,)? }) => {
+        #[::rstest::rstest]
+        $(#[case::$name($crate::example::Example::new($part, $input, $expected))])+
+        fn examples(#[case] example: $crate::example::Example) {
+            example.assert::<$day>();
+        }
+    };
+    ($day:ty, $args:ty { $($name:ident: $part:expr, $input:expr, $arg:expr => $expected:expr),+ $(,)? }) => {
+        #[::rstest::rstest]
+        $(#[case::$name($crate::example::Example::<$args>::with_args($part, $input, $arg, $expected))])+
+        fn examples(#[case] example: $crate::example::Example<$args>) {
+            example.assert_with::<$day>();
+        }
+    };
+    ($day:ty, $args:ty { $($name:ident: $part:expr, $input:expr => $expected:expr),+ $(,)? }) => {
+        #[::rstest::rstest]
+        $(#[case::$name($crate::example::Example::<$args>::with_default_args::<$day>($part, $input, $expected))])+
+        fn examples(#[case] example: $crate::example::Example<$args>) {
+            example.assert_with::<$day>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Day(usize);
+
+    impl FromStr for Day {
+        type Err = SolutionReport;
+
+        fn from_str(input: &str) -> SolutionResult<Self> {
+            Ok(Self(input.lines().count()))
+        }
+    }
+
+    impl Solution for Day {
+        type Output = usize;
+
+        fn part1(&mut self) -> SolutionResult<Self::Output> {
+            Ok(self.0)
+        }
+
+        fn part2(&mut self) -> SolutionResult<Self::Output> {
+            Ok(self.0 * 2)
+        }
+    }
+
+    impl ArgSolution<usize> for Day {
+        type Output = usize;
+
+        fn part1(&mut self, times: usize) -> SolutionResult<Self::Output> {
+            Ok(self.0 * times)
+        }
+    }
+
+    crate::examples!(Day {
+        one_line: Part::One, "a" => 1,
+        two_lines: Part::One, "a\nb" => 2,
+        doubled: Part::Two, "a\nb" => 4,
+    });
+
+    mod with_args {
+        use super::*;
+
+        crate::examples!(Day, usize {
+            thrice: Part::One, "a\nb", 3 => 6,
+        });
+    }
+
+    impl DefaultArgs<usize> for Day {
+        fn args(_part: Part) -> usize {
+            100
+        }
+
+        fn example_args(_part: Part) -> usize {
+            5
+        }
+    }
+
+    mod default_args {
+        use super::*;
+
+        crate::examples!(Day, usize {
+            five_times: Part::One, "a\nb" => 10,
+        });
+    }
+
+    mod borrowed {
+        use super::*;
+
+        struct Day<'a>(Vec<&'a str>);
+
+        impl<'a> Parse<'a> for Day<'a> {
+            type Err = SolutionReport;
+
+            fn parse(input: &'a str) -> SolutionResult<Self> {
+                Ok(Self(input.lines().collect()))
+            }
+        }
+
+        impl Solution for Day<'_> {
+            type Output = String;
+
+            fn part1(&mut self) -> SolutionResult<Self::Output> {
+                Ok(self.0.concat())
+            }
+        }
+
+        crate::examples!(Day {
+            joined: Part::One, "a\nb" => "ab",
+        });
+    }
+
+    #[test]
+    fn test_sidecar() {
+        let path = std::env::temp_dir().join("aoc-ornaments-sidecar.examples");
+        std::fs::write(&path, "=== part 1 -> 2\na\nb\n\n=== part 2 -> 2\na\n").unwrap();
+
+        let examples = Example::load(&path).unwrap();
+        assert_eq!(examples, vec![
+            Example::new(Part::One, "a\nb", 2),
+            Example::new(Part::Two, "a", 2),
+        ]);
+        assert!(Example::<usize>::load_with(&path).is_err());
+
+        std::fs::write(&path, Example::sidecar(&examples)).unwrap();
+        assert_eq!(Example::load(&path).unwrap(), examples);
+
+        // blank lines and spaces that belong to the input are kept
+        std::fs::write(&path, "=== part 1 -> 3\na\n\n\n\n=== part 2 -> 1\n  a  \n\n").unwrap();
+        let examples = Example::load(&path).unwrap();
+        assert_eq!(examples, vec![
+            Example::new(Part::One, "a\n\n", 3),
+            Example::new(Part::Two, "  a  \n", 1),
+        ]);
+        std::fs::write(&path, Example::sidecar(&examples)).unwrap();
+        assert_eq!(Example::load(&path).unwrap(), examples);
+
+        std::fs::write(&path, "=== part 1 with 3 -> 6\na\nb\n").unwrap();
+        let examples = Example::<usize>::load_with(&path).unwrap();
+        examples[0].assert_with::<Day>();
+        assert!(Example::load(&path).is_err());
+
+        // as written from a puzzle page, without arguments
+        std::fs::write(&path, "=== part 1 -> 10\na\nb\n\n=== part 1 with 3 -> 6\na\nb\n").unwrap();
+        assert!(Example::<usize>::load_with(&path).is_err());
+        let examples = Example::<usize>::load_with_defaults::<Day>(&path).unwrap();
+        assert_eq!(examples.iter().map(|example| example.args).collect::<Vec<_>>(), [5, 3]);
+        examples.iter().for_each(Example::assert_with::<Day>);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
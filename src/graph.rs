@@ -94,6 +94,10 @@ impl<T: std::ops::Add<Output = T> + Clone + Copy + Ord + Default> TravelingSales
         let mut best_path = None;
 
         for start in set.iter() {
+            if crate::cancel::is_cancelled() {
+                return None;
+            }
+
             let mut remaining = set.clone();
             remaining.remove(start); // Remove starting city from remaining set
 
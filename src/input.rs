@@ -0,0 +1,158 @@
+//! Find puzzle input at runtime
+//!
+//! Inputs are personal and never committed, so they can't be `include_str!`'d without breaking a fresh clone.
+//!
+//! File names follow the sites' own conventions:
+//!
+//! - AoC: `2015-12-04.txt`
+//! - everybody.codes: `everybody_codes_e2024_q01_p1.txt`, one file per part
+
+use std::{io::Read, path::{Path, PathBuf}, sync::{Mutex, PoisonError}};
+
+use thiserror::Error;
+
+use crate::{puzzle::PuzzleId, Part, SolutionResult};
+
+/// Overrides the directory searched first
+pub const ENV: &str = "AOC_INPUTS";
+
+/// Searched in order, relative to the working directory
+pub const DIRS: [&str; 3] = ["inputs", "src/inputs", "examples/inputs"];
+
+#[derive(Error, Debug)]
+#[cfg_attr(feature = "miette", derive(miette::Diagnostic))]
+pub enum InputError {
+    #[error("No input found for {id} {part}")]
+    #[cfg_attr(feature = "miette", diagnostic(code(input::missing), help("looked for:\n{looked}")))]
+    Missing { id: PuzzleId, part: Part, looked: String },
+
+    #[error("Failed to read {}", path.display())]
+    #[cfg_attr(feature = "miette", diagnostic(code(input::read)))]
+    Read { path: PathBuf, #[source] source: std::io::Error },
+}
+
+/// Where puzzle input comes from
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    /// the same file for every puzzle
+    Path(PathBuf),
+    /// read once, the same text for every puzzle
+    Stdin,
+    /// each directory is searched for the puzzle's file name
+    Dirs(Vec<PathBuf>),
+}
+
+impl Default for Input {
+    /// [ENV] if set, then [DIRS]
+    fn default() -> Self {
+        let env = std::env::var_os(ENV).map(PathBuf::from);
+
+        Self::Dirs(env.into_iter().chain(DIRS.iter().map(PathBuf::from)).collect())
+    }
+}
+
+impl Input {
+    /// Search a single directory
+    pub fn dir(path: impl Into<PathBuf>) -> Self {
+        Self::Dirs(vec![path.into()])
+    }
+
+    /// Candidate file names, most specific first
+    pub fn file_names(id: &PuzzleId, part: Part) -> Vec<String> {
+        match id {
+            PuzzleId::Aoc { .. } => vec![format!("{id}.txt")],
+            PuzzleId::Everybody { .. } => vec![format!("{id}_p{}.txt", part.number()), format!("{id}.txt")],
+        }
+    }
+
+    /// The file that would be read, if any
+    pub fn find(&self, id: &PuzzleId, part: Part) -> Result<Option<PathBuf>, InputError> {
+        match self {
+            Self::Path(path) => Ok(Some(path.clone())),
+            Self::Stdin => Ok(None),
+            Self::Dirs(dirs) => {
+                let mut looked = Vec::new();
+
+                for dir in dirs {
+                    for name in Self::file_names(id, part) {
+                        let path = dir.join(name);
+                        if path.is_file() {
+                            return Ok(Some(path));
+                        }
+                        looked.push(path.display().to_string());
+                    }
+                }
+
+                Err(InputError::Missing { id: *id, part, looked: looked.join("\n") })
+            },
+        }
+    }
+
+    pub fn load(&self, id: &PuzzleId, part: Part) -> SolutionResult<String> {
+        let input = match self.find(id, part)? {
+            Some(path) => read(&path)?,
+            None => stdin()?,
+        };
+
+        Ok(input)
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path)
+        .map_err(|source| InputError::Read { path: path.to_path_buf(), source })
+}
+
+/// stdin can only be consumed once, the lock is held while reading so parallel parts wait for the first rather than find it empty
+fn stdin() -> Result<String, InputError> {
+    static STDIN: Mutex<Option<String>> = Mutex::new(None);
+
+    let mut stdin = STDIN.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(input) = stdin.as_ref() {
+        return Ok(input.clone());
+    }
+
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)
+        .map_err(|source| InputError::Read { path: "<stdin>".into(), source })?;
+
+    Ok(stdin.insert(input).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_names() {
+        assert_eq!(Input::file_names(&PuzzleId::aoc(2015, 4), Part::Two), vec!["2015-12-04.txt"]);
+        assert_eq!(Input::file_names(&PuzzleId::everybody(2024, 1), Part::Three), vec![
+            "everybody_codes_e2024_q01_p3.txt",
+            "everybody_codes_e2024_q01.txt",
+        ]);
+    }
+
+    #[test]
+    fn test_missing() {
+        let input = Input::Dirs(vec!["nowhere".into(), "nor/here".into()]);
+
+        match input.find(&PuzzleId::aoc(2015, 4), Part::One) {
+            Err(InputError::Missing { looked, .. }) => assert_eq!(looked, "nowhere/2015-12-04.txt\nnor/here/2015-12-04.txt"),
+            other => panic!("expected missing input, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_dirs() {
+        let dir = std::env::temp_dir().join("aoc-ornaments-input-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("everybody_codes_e2024_q01_p2.txt"), "notes").unwrap();
+
+        let input = Input::Dirs(vec!["nowhere".into(), dir.clone()]);
+        assert_eq!(input.load(&PuzzleId::everybody(2024, 1), Part::Two).unwrap(), "notes");
+        assert!(input.load(&PuzzleId::everybody(2024, 1), Part::One).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,27 +1,80 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
+pub mod bench;
 pub mod bits;
+pub mod cancel;
+pub mod error;
+pub mod example;
 pub mod intcode;
 pub mod graph;
+pub mod input;
 pub mod nom;
+pub mod page;
+pub mod puzzle;
+pub mod rejected;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
 pub mod scoring;
 pub mod spatial;
+pub mod verify;
+
+pub use error::SolutionError;
+pub use puzzle::Part;
+
+/// Parses puzzle input into something that may borrow from it
+///
+/// Every [FromStr] type gets this for free, implement it directly to keep `&'a str` slices (from nom, say) without leaking or cloning:
+///
+/// ```
+/// use aoc_ornaments::{Parse, SolutionReport};
+///
+/// struct Day<'a>(Vec<&'a str>);
+///
+/// impl<'a> Parse<'a> for Day<'a> {
+///     type Err = SolutionReport;
+///
+///     fn parse(input: &'a str) -> Result<Self, Self::Err> {
+///         Ok(Self(input.lines().collect()))
+///     }
+/// }
+/// ```
+pub trait Parse<'a>: Sized {
+    type Err;
+
+    fn parse(input: &'a str) -> Result<Self, Self::Err>;
+}
+
+impl<'a, T: FromStr> Parse<'a> for T {
+    type Err = T::Err;
+
+    fn parse(input: &'a str) -> Result<Self, Self::Err> {
+        input.parse()
+    }
+}
+
+/// Parses the notes for a single part, everybody.codes gives each part its own which often need parsing differently
+///
+/// Implement this instead of [FromStr]/[Parse], the runner finds each part's notes file (`everybody_codes_e2024_q01_p2.txt`)
+pub trait Notes<'a>: Sized {
+    fn parse_notes(notes: &'a str, part: Part) -> SolutionResult<Self>;
+}
 
 /// Shared behavior amongst AOC/Everybody.codes solutions
 ///
-/// [FromStr] is a supertrait because we ALWAYS have to parse string input
-pub trait Solution: FromStr {
-    /// Ensures the output can be converted to a string
-    type Output: std::fmt::Display + Default;
+/// We ALWAYS have to parse string input, either with [FromStr] or with [Parse] to borrow from it
+pub trait Solution {
+    /// Ensures the output can be converted to a string, see [MixedSolution] when the parts answer with different types
+    type Output: Display + Default;
 
-    /// Required for AoC
+    /// Required for AoC, [SolutionError::Unimplemented] until then
     fn part1(&mut self) -> SolutionResult<Self::Output> {
-        todo!()
+        Err(SolutionError::Unimplemented(Part::One).into())
     }
 
-    /// Required for AoC
+    /// Required for AoC, [SolutionError::Unimplemented] until then
     fn part2(&mut self) -> SolutionResult<Self::Output> {
-        todo!()
+        Err(SolutionError::Unimplemented(Part::Two).into())
     }
 
     /// Optional, for everybody.codes or bonus AoC
@@ -36,21 +89,86 @@ pub trait Solution: FromStr {
             Part::Three => self.part3()?.to_string(),
         })
     }
+
+    /// Parse and solve, so no part ever sees the state another part left behind
+    fn solve_fresh<'a>(input: &'a str, which: Part) -> SolutionResult<String>
+    where
+        Self: Parse<'a>,
+        <Self as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        <Self as Parse>::parse(input).map_err(Into::into)?.solve(which)
+    }
+
+    /// Parse a part's own notes, then solve it
+    fn solve_notes<'a>(notes: &'a str, which: Part) -> SolutionResult<String>
+    where
+        Self: Notes<'a>,
+    {
+        Self::parse_notes(notes, which)?.solve(which)
+    }
+
+    /// Parse once, solve as many parts as you like from a clean copy
+    fn solve_clone(&self, which: Part) -> SolutionResult<String>
+    where
+        Self: Clone,
+    {
+        self.clone().solve(which)
+    }
+}
+
+/// A [Solution] whose parts answer with different types, e.g. a number then a password
+///
+/// Implement this instead of [Solution], every part's answer is a string once it gets there.
+pub trait MixedSolution {
+    type Output1: Display;
+    type Output2: Display;
+    type Output3: Display + Default;
+
+    /// see [Solution::part1]
+    fn part1(&mut self) -> SolutionResult<Self::Output1> {
+        Err(SolutionError::Unimplemented(Part::One).into())
+    }
+
+    /// see [Solution::part2]
+    fn part2(&mut self) -> SolutionResult<Self::Output2> {
+        Err(SolutionError::Unimplemented(Part::Two).into())
+    }
+
+    /// see [Solution::part3]
+    fn part3(&mut self) -> SolutionResult<Self::Output3> {
+        Ok(Self::Output3::default())
+    }
+}
+
+impl<T: MixedSolution> Solution for T {
+    type Output = String;
+
+    fn part1(&mut self) -> SolutionResult<String> {
+        MixedSolution::part1(self).map(|answer| answer.to_string())
+    }
+
+    fn part2(&mut self) -> SolutionResult<String> {
+        MixedSolution::part2(self).map(|answer| answer.to_string())
+    }
+
+    fn part3(&mut self) -> SolutionResult<String> {
+        MixedSolution::part3(self).map(|answer| answer.to_string())
+    }
 }
 
 /// accepts a signle argument, which may be a tuple
-pub trait ArgSolution<A>: FromStr {
-    /// Ensures the output can be converted to a string
-    type Output: std::fmt::Display + Default;
+pub trait ArgSolution<A> {
+    /// Ensures the output can be converted to a string, see [MixedArgSolution] when the parts answer with different types
+    type Output: Display + Default;
 
-    /// Required for AoC
+    /// Required for AoC, [SolutionError::Unimplemented] until then
     fn part1(&mut self, _args: A) -> SolutionResult<Self::Output> {
-        todo!()
+        Err(SolutionError::Unimplemented(Part::One).into())
     }
 
-    /// Required for AoC
+    /// Required for AoC, [SolutionError::Unimplemented] until then
     fn part2(&mut self, _args: A) -> SolutionResult<Self::Output> {
-        todo!()
+        Err(SolutionError::Unimplemented(Part::Two).into())
     }
 
     /// Optional, for everybody.codes or bonus AoC
@@ -65,23 +183,105 @@ pub trait ArgSolution<A>: FromStr {
             Part::Three => ArgSolution::part3(self, args)?.to_string(),
         })
     }
+
+    /// see [Solution::solve_fresh]
+    fn solve_fresh<'a>(input: &'a str, which: Part, args: A) -> SolutionResult<String>
+    where
+        Self: Parse<'a>,
+        <Self as Parse<'a>>::Err: Into<SolutionReport>,
+    {
+        <Self as Parse>::parse(input).map_err(Into::into)?.solve(which, args)
+    }
+
+    /// see [Solution::solve_clone]
+    fn solve_clone(&self, which: Part, args: A) -> SolutionResult<String>
+    where
+        Self: Clone,
+    {
+        self.clone().solve(which, args)
+    }
 }
 
-/// Puzzle parts
-#[derive(Debug, Clone, Copy)]
-pub enum Part {
-    /// AoC
-    One,
-    /// AoC
-    Two,
-    /// for everybody.codes
-    Three,
+/// An [ArgSolution] whose parts answer with different types, see [MixedSolution]
+pub trait MixedArgSolution<A> {
+    type Output1: Display;
+    type Output2: Display;
+    type Output3: Display + Default;
+
+    /// see [ArgSolution::part1]
+    fn part1(&mut self, _args: A) -> SolutionResult<Self::Output1> {
+        Err(SolutionError::Unimplemented(Part::One).into())
+    }
+
+    /// see [ArgSolution::part2]
+    fn part2(&mut self, _args: A) -> SolutionResult<Self::Output2> {
+        Err(SolutionError::Unimplemented(Part::Two).into())
+    }
+
+    /// see [ArgSolution::part3]
+    fn part3(&mut self, _args: A) -> SolutionResult<Self::Output3> {
+        Ok(Self::Output3::default())
+    }
 }
 
+impl<A, T: MixedArgSolution<A>> ArgSolution<A> for T {
+    type Output = String;
+
+    fn part1(&mut self, args: A) -> SolutionResult<String> {
+        MixedArgSolution::part1(self, args).map(|answer| answer.to_string())
+    }
+
+    fn part2(&mut self, args: A) -> SolutionResult<String> {
+        MixedArgSolution::part2(self, args).map(|answer| answer.to_string())
+    }
+
+    fn part3(&mut self, args: A) -> SolutionResult<String> {
+        MixedArgSolution::part3(self, args).map(|answer| answer.to_string())
+    }
+}
+
+/// The arguments an [ArgSolution] needs for each part, so callers don't have to know the magic numbers
+///
+/// ```ignore
+/// impl DefaultArgs<u32> for Day {
+///     fn args(_part: Part) -> u32 {
+///         150
+///     }
+///
+///     fn example_args(_part: Part) -> u32 {
+///         25
+///     }
+/// }
+/// ```
+pub trait DefaultArgs<A>: ArgSolution<A> {
+    /// for the real puzzle input
+    fn args(part: Part) -> A;
+
+    /// for the examples in the puzzle text, the same as the real input unless overridden
+    fn example_args(part: Part) -> A {
+        Self::args(part)
+    }
+}
+
+/// `miette::Result` with the default `miette` feature, otherwise any boxed error, which anyhow and eyre both accept
 #[cfg(feature = "miette")]
 pub type SolutionResult<T> = miette::Result<T>;
 #[cfg(not(feature = "miette"))]
-pub type SolutionResult<T> = Result<T, Box<dyn std::error::Error>>;
+pub type SolutionResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// The error side of [SolutionResult]
+#[cfg(feature = "miette")]
+pub type SolutionReport = miette::Report;
+#[cfg(not(feature = "miette"))]
+pub type SolutionReport = Box<dyn std::error::Error + Send + Sync>;
+
+/// ad-hoc error message, whichever [SolutionReport] is in use
+pub(crate) fn report(message: String) -> SolutionReport {
+    #[cfg(feature = "miette")]
+    return miette::miette!("{message}");
+    #[cfg(not(feature = "miette"))]
+    return message.into();
+}
 
 #[cfg(test)]
 mod tests {
@@ -92,25 +292,25 @@ mod tests {
     impl Solution for Day {
         type Output = String;
 
-        fn part1(&mut self) -> miette::Result<Self::Output> {
+        fn part1(&mut self) -> SolutionResult<Self::Output> {
             Ok("Hello, Rudolph!".into())
         }
 
-        fn part2(&mut self) -> miette::Result<Self::Output> {
+        fn part2(&mut self) -> SolutionResult<Self::Output> {
             Ok("Hello, Santa!".into())
         }
     }
 
     impl FromStr for Day {
-        type Err = miette::Error;
+        type Err = SolutionReport;
 
-        fn from_str(_input: &str) -> miette::Result<Self> {
+        fn from_str(_input: &str) -> SolutionResult<Self> {
             Ok(Self)
         }
     }
 
     #[test]
-    fn test_part_1() -> miette::Result<()> {
+    fn test_part_1() -> SolutionResult<()> {
         let mut day = Day::from_str("")?;
 
         let solution = day.solve(Part::One)?;
@@ -119,7 +319,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_2() -> miette::Result<()> {
+    fn test_part_2() -> SolutionResult<()> {
         let mut day: Day = "".parse()?;
 
         let solution = day.solve(Part::Two)?;
@@ -127,6 +327,146 @@ mod tests {
         Ok(())
     }
 
+    /// part 2 only works from a clean state
+    #[derive(Clone)]
+    struct Counter(usize);
+
+    impl Solution for Counter {
+        type Output = usize;
+
+        fn part1(&mut self) -> SolutionResult<Self::Output> {
+            self.0 += 1;
+            Ok(self.0)
+        }
+
+        fn part2(&mut self) -> SolutionResult<Self::Output> {
+            self.0 += 2;
+            Ok(self.0)
+        }
+    }
+
+    impl FromStr for Counter {
+        type Err = SolutionReport;
+
+        fn from_str(input: &str) -> SolutionResult<Self> {
+            Ok(Self(input.len()))
+        }
+    }
+
+    #[test]
+    fn test_fresh_state() -> SolutionResult<()> {
+        let mut dirty = Counter::from_str("")?;
+        dirty.solve(Part::One)?;
+        assert_eq!(dirty.solve(Part::Two)?, "3");
+
+        assert_eq!(Counter::solve_fresh("", Part::Two)?, "2");
+
+        let counter = Counter::from_str("")?;
+        assert_eq!(counter.solve_clone(Part::One)?, "1");
+        assert_eq!(counter.solve_clone(Part::Two)?, "2");
+
+        Ok(())
+    }
+
+    /// nothing written yet
+    struct Empty;
+
+    impl Solution for Empty {
+        type Output = u8;
+    }
+
+    impl FromStr for Empty {
+        type Err = SolutionReport;
+
+        fn from_str(_input: &str) -> SolutionResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    /// a count, then a password
+    struct Mixed;
+
+    impl MixedSolution for Mixed {
+        type Output1 = usize;
+        type Output2 = String;
+        type Output3 = usize;
+
+        fn part1(&mut self) -> SolutionResult<Self::Output1> {
+            Ok(42)
+        }
+
+        fn part2(&mut self) -> SolutionResult<Self::Output2> {
+            Ok("abcdffaa".into())
+        }
+    }
+
+    impl FromStr for Mixed {
+        type Err = SolutionReport;
+
+        fn from_str(_input: &str) -> SolutionResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    fn test_output_per_part() -> SolutionResult<()> {
+        assert_eq!(Mixed::solve_fresh("", Part::One)?, "42");
+        assert_eq!(Mixed::solve_fresh("", Part::Two)?, "abcdffaa");
+        assert_eq!(Mixed::solve_fresh("", Part::Three)?, "0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unimplemented() {
+        let error = Empty.solve(Part::Two).unwrap_err();
+        assert!(SolutionError::is_unimplemented(&error));
+
+        assert_eq!(Empty.solve(Part::Three).unwrap(), "0");
+    }
+
+    /// each part's notes are a different shape
+    enum Quest {
+        Words(Vec<String>),
+        Numbers(Vec<u32>),
+    }
+
+    impl Notes<'_> for Quest {
+        fn parse_notes(notes: &str, part: Part) -> SolutionResult<Self> {
+            Ok(match part {
+                Part::One => Self::Words(notes.split(',').map(String::from).collect()),
+                _ => Self::Numbers(notes.split(',').map(|n| n.parse().map_err(|_| SolutionError::unsolvable(n))).collect::<Result<_, _>>()?),
+            })
+        }
+    }
+
+    impl Solution for Quest {
+        type Output = usize;
+
+        fn part1(&mut self) -> SolutionResult<Self::Output> {
+            match self {
+                Self::Words(words) => Ok(words.len()),
+                Self::Numbers(_) => unreachable!(),
+            }
+        }
+
+        fn part2(&mut self) -> SolutionResult<Self::Output> {
+            match self {
+                Self::Numbers(numbers) => Ok(numbers.iter().sum::<u32>() as usize),
+                Self::Words(_) => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_notes() -> SolutionResult<()> {
+        assert_eq!(Quest::solve_notes("a,b,c", Part::One)?, "3");
+        assert_eq!(Quest::solve_notes("1,2,3", Part::Two)?, "6");
+        assert!(Quest::solve_notes("a,b,c", Part::Two).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_part_3() {
         let mut day = Day;
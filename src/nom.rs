@@ -1,41 +1,64 @@
 //!
-//! 
-//! ## working with [miette]
-//! 
+//!
+//! ## working with [SolutionError]
+//!
 //! since nom is usually zero-copy and relies on borrowed data, you may get errors like:
-//! 
+//!
 //! > borrowed data escapes outside of associated function
-//! 
+//!
 //! when using:
-//! 
+//!
 //! ```ignore
 //! .map_err(|e| miette::miette!(e))?;
 //! ```
-//! 
+//!
 //! The problem is that `e` is a borrowed reference to the input data, and it's being returned from the function.
-//! 
+//!
 //! the fix:
-//! 
+//!
 //! ```ignore
-//! .map_err(|e| miette::miette!(e.to_owned()))?;
+//! .map_err(|e| aoc_ornaments::nom::error(input, e))?;
 //! ```
-//! 
-//! if a new string is allocated (or owership is taken) then the borrowed reference is no longer needed and the error goes away.
-//! 
-//! you can also:
-//! 
+//!
+//! which copies the input into a [SolutionError] that points at where parsing stopped, whether or not miette is enabled.
+//! With miette you can also allocate a new string yourself:
+//!
 //! ```ignore
 //! .map_err(|e| miette::miette!("Failed to parse input: {e}"))
 //! ```
-//! 
-//! which allocates a new string, similar to format! macro.
 
 use nom::{character::complete::{newline, not_line_ending}, multi::separated_list0};
 
+use crate::SolutionError;
+
 // type IResult<'a, O> = nom::IResult<&'a str, O, nom::error::Error<&'a str>>;
 
-/// outputs a miette result for use in FromStr, otherwise you don't need the turbofish and .expect
-pub fn split_newlines(input: &str) -> miette::Result<(&str, Vec<&str>)> {
+/// An owned error, pointing at where in `input` the parser gave up
+pub fn error(input: &str, e: nom::Err<nom::error::Error<&str>>) -> SolutionError {
+    match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => SolutionError::at(input, e.input, format!("expected {}", e.code.description())),
+        nom::Err::Incomplete(_) => SolutionError::at(input, &input[input.len()..], "unexpected end of input"),
+    }
+}
+
+/// for use in FromStr, otherwise you don't need the turbofish and .expect
+pub fn split_newlines(input: &str) -> Result<(&str, Vec<&str>), SolutionError> {
     separated_list0(newline::<&str, nom::error::Error<&str>>, not_line_ending)(input)
-        .map_err(|e| miette::miette!("Failed to parse input: {e}"))
-}
\ No newline at end of file
+        .map_err(|e| error(input, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{bytes::complete::tag, IResult};
+
+    use super::*;
+
+    #[test]
+    fn test_error() {
+        let input = "a\nb";
+        let result: IResult<&str, &str> = tag("b")(input);
+
+        assert_eq!(error(input, result.unwrap_err()), SolutionError::parse(input, 0..1, "expected Tag"));
+        assert_eq!(split_newlines(input).unwrap(), ("", vec!["a", "b"]));
+    }
+}
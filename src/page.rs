@@ -0,0 +1,172 @@
+//! Examples from a saved puzzle page, without going online
+//!
+//! Save the page once part 1 is solved, so both parts are on it, next to the inputs as `src/inputs/2015-12-06.html`.
+//! `cargo run -- new 2015 6` then fills the day's `.examples` file from it, or point at a page with `--page FILE`.
+//!
+//! Examples live in `<pre><code>` blocks and answers are emphasized as `<code><em>`, but which goes with which is a guess:
+//! each part gets its first block (or the last one before it) and its last answer. Check them before trusting the tests.
+
+use crate::{example::Example, Part};
+
+/// Everything that might be an example in one part's description
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub part: Part,
+    /// `<pre><code>` blocks, as plain text
+    pub blocks: Vec<String>,
+    /// emphasized `<code><em>` values outside of the blocks
+    pub answers: Vec<String>,
+}
+
+/// One section per `<article>`, or the whole page as part 1 if it has none
+pub fn sections(html: &str) -> Vec<Section> {
+    let articles: Vec<&str> = match html.split_once("<article") {
+        Some(_) => html.split("<article").skip(1).collect(),
+        None => vec![html],
+    };
+
+    articles.into_iter().zip(Part::ALL).map(|(article, part)| {
+        let article = article.split("</article>").next().unwrap_or(article);
+        let blocks = between(article, "<pre><code>", "</code></pre>");
+
+        let mut prose = article.to_string();
+        for block in &blocks {
+            prose = prose.replacen(block, "", 1);
+        }
+
+        Section {
+            part,
+            blocks: blocks.into_iter().map(text).collect(),
+            answers: between(&prose, "<code><em>", "</em></code>").into_iter().map(text).collect(),
+        }
+    }).collect()
+}
+
+/// The best guess at each part's example, parts without an answer are left out
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut input = None;
+
+    sections(html).into_iter().filter_map(|section| {
+        // part 2 usually reuses part 1's example
+        if let Some(block) = section.blocks.first() {
+            input = Some(block.clone());
+        }
+
+        Some(Example::new(section.part, input.as_deref()?, section.answers.last()?))
+    }).collect()
+}
+
+/// Every piece of `text` between `open` and `close`
+fn between<'a>(text: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+
+    while let Some((_, after)) = rest.split_once(open) {
+        let Some((inside, after)) = after.split_once(close) else { break };
+        found.push(inside);
+        rest = after;
+    }
+
+    found
+}
+
+/// Drops tags, decodes entities and the trailing newline
+fn text(html: &str) -> String {
+    let mut stripped = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some((before, after)) = rest.split_once('<') {
+        stripped.push_str(before);
+        rest = after.split_once('>').map_or("", |(_, after)| after);
+    }
+    stripped.push_str(rest);
+
+    decode(&stripped).trim_end_matches('\n').to_string()
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some((before, after)) = rest.split_once('&') {
+        decoded.push_str(before);
+
+        let entity = after.split_once(';').and_then(|(name, after)| Some((entity(name)?, after)));
+        match entity {
+            Some((c, after)) => {
+                decoded.push(c);
+                rest = after;
+            },
+            None => {
+                decoded.push('&');
+                rest = after;
+            },
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 3: Perfectly Spherical Houses in a Vacuum ---</h2>
+<p>For example:</p>
+<ul><li><code>&gt;</code> delivers presents to <code><em>2</em></code> houses.</li></ul>
+<pre><code>^&gt;v&lt;
+<em>^v</em>
+</code></pre>
+<p>delivers presents to <code><em>4</em></code> houses.</p>
+</article>
+<p>Your puzzle answer was <code>2565</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>now delivers presents to <code><em>3</em></code> houses.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections(PAGE), vec![
+            Section { part: Part::One, blocks: vec!["^>v<\n^v".into()], answers: vec!["2".into(), "4".into()] },
+            Section { part: Part::Two, blocks: vec![], answers: vec!["3".into()] },
+        ]);
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(examples(PAGE), vec![
+            Example::new(Part::One, "^>v<\n^v", 4),
+            Example::new(Part::Two, "^>v<\n^v", 3),
+        ]);
+        assert_eq!(examples("<pre><code>1\n2\n</code></pre> is <code><em>3</em></code>"), vec![Example::new(Part::One, "1\n2", 3)]);
+        assert!(examples("<p>no examples</p>").is_empty());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("a &amp;&lt;b&gt; &#65;&#x42; &unknown; & c\n"), "a &<b> AB &unknown; & c");
+        assert_eq!(text("<em>x</em>y<span class=\"z\">z</span>"), "xyz");
+    }
+}
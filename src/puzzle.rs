@@ -0,0 +1,203 @@
+//! Names for puzzles and their parts, shared by the runner, input loading and reports
+//!
+//! Formats follow each site's own naming:
+//!
+//! - AoC: `2015-12-04`
+//! - everybody.codes: `everybody_codes_e2024_q01`
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// Quests in an everybody.codes event
+pub const QUESTS: u8 = 20;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "miette", derive(miette::Diagnostic))]
+pub enum IdError {
+    #[error("Invalid part `{0}`")]
+    #[cfg_attr(feature = "miette", diagnostic(code(puzzle::part), help("expected 1, 2 or 3")))]
+    Part(String),
+
+    #[error("Invalid puzzle `{0}`")]
+    #[cfg_attr(feature = "miette", diagnostic(code(puzzle::id), help("expected YYYY-12-DD or everybody_codes_eYYYY_qNN")))]
+    Puzzle(String),
+}
+
+/// Puzzle parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    /// AoC
+    One,
+    /// AoC
+    Two,
+    /// for everybody.codes
+    Three,
+}
+
+impl Part {
+    pub const ALL: [Part; 3] = [Part::One, Part::Two, Part::Three];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+            Part::Three => 3,
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = Part> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "Part 1"),
+            Part::Two => write!(f, "Part 2"),
+            Part::Three => write!(f, "Part 3"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = IdError;
+
+    /// `2`, `p2`, `part2` or `Part 2`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase();
+        let number = lower.strip_prefix("part").or_else(|| lower.strip_prefix('p')).unwrap_or(&lower);
+
+        match number.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "3" => Ok(Part::Three),
+            _ => Err(IdError::Part(s.to_string())),
+        }
+    }
+}
+
+/// Identifies a single puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PuzzleId {
+    /// Advent of Code
+    Aoc { year: u16, day: u8 },
+    /// everybody.codes
+    Everybody { event: u16, quest: u8 },
+}
+
+impl PuzzleId {
+    pub fn aoc(year: u16, day: u8) -> Self {
+        Self::Aoc { year, day }
+    }
+
+    pub fn everybody(event: u16, quest: u8) -> Self {
+        Self::Everybody { event, quest }
+    }
+
+    /// the parts a puzzle has, AoC only has two(2)
+    pub fn parts(&self) -> &'static [Part] {
+        match self {
+            Self::Aoc { .. } => &Part::ALL[..2],
+            Self::Everybody { .. } => &Part::ALL,
+        }
+    }
+
+    /// Every day of an AoC year, twelve(12) from 2025 onwards
+    pub fn days(year: u16) -> impl Iterator<Item = Self> {
+        (1..=last_day(year)).map(move |day| Self::aoc(year, day))
+    }
+
+    /// Every quest of an everybody.codes event
+    pub fn quests(event: u16) -> impl Iterator<Item = Self> {
+        (1..=QUESTS).map(move |quest| Self::everybody(event, quest))
+    }
+}
+
+fn last_day(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+impl std::fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Aoc { year, day } => write!(f, "{year}-12-{day:02}"),
+            Self::Everybody { event, quest } => write!(f, "everybody_codes_e{event}_q{quest:02}"),
+        }
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = IdError;
+
+    /// the same names [Display](std::fmt::Display) gives
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IdError::Puzzle(s.to_string());
+
+        if let Some(rest) = s.strip_prefix("everybody_codes_e") {
+            let (event, quest) = rest.split_once("_q").ok_or_else(invalid)?;
+            let quest: u8 = quest.parse().map_err(|_| invalid())?;
+
+            if !(1..=QUESTS).contains(&quest) {
+                return Err(invalid());
+            }
+
+            return Ok(Self::everybody(event.parse().map_err(|_| invalid())?, quest));
+        }
+
+        let [year, "12", day] = s.splitn(3, '-').collect::<Vec<_>>()[..] else {
+            return Err(invalid());
+        };
+        let year: u16 = year.parse().map_err(|_| invalid())?;
+        let day: u8 = day.parse().map_err(|_| invalid())?;
+
+        if !(1..=last_day(year)).contains(&day) {
+            return Err(invalid());
+        }
+
+        Ok(Self::aoc(year, day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(PuzzleId::aoc(2015, 4).to_string(), "2015-12-04");
+        assert_eq!(PuzzleId::everybody(2024, 1).to_string(), "everybody_codes_e2024_q01");
+    }
+
+    #[test]
+    fn test_from_str() {
+        for id in [PuzzleId::aoc(2015, 4), PuzzleId::aoc(2024, 25), PuzzleId::aoc(2025, 12), PuzzleId::everybody(2024, 1), PuzzleId::everybody(2024, 20)] {
+            assert_eq!(id.to_string().parse::<PuzzleId>(), Ok(id));
+        }
+
+        for invalid in ["2015-11-04", "2015-12-26", "2015-12-00", "2015", "everybody_codes_e2024", "everybody_codes_e2024_q00", "2025-12-13", "2025-12-20", "everybody_codes_e2024_q21", "everybody_codes_e2024_q99"] {
+            assert!(invalid.parse::<PuzzleId>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_part() {
+        for part in Part::iter() {
+            assert_eq!(part.to_string().parse::<Part>(), Ok(part));
+            assert_eq!(part.number().to_string().parse::<Part>(), Ok(part));
+        }
+
+        assert_eq!("p3".parse::<Part>(), Ok(Part::Three));
+        assert!("4".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_iteration() {
+        assert_eq!(PuzzleId::days(2015).count(), 25);
+        assert_eq!(PuzzleId::days(2025).last(), Some(PuzzleId::aoc(2025, 12)));
+        assert_eq!(PuzzleId::quests(2024).next(), Some(PuzzleId::everybody(2024, 1)));
+        assert!(PuzzleId::aoc(2015, 25) < PuzzleId::aoc(2016, 1));
+        assert!(PuzzleId::aoc(2024, 1) < PuzzleId::everybody(2024, 1));
+    }
+}
@@ -0,0 +1,253 @@
+//! Answers the puzzle turned down, so the same mistake isn't submitted twice
+//!
+//! Stored as JSON, keyed by puzzle, part then answer: `{"2015-12-04": {"1": {"300000": "too high", "abc": "wrong"}}}`
+//!
+//! ```sh
+//! cargo run -- reject 2015 4 1 300000 --too-high --rejected rejected.json
+//! cargo run -- 2015 4 --rejected rejected.json
+//! ```
+//!
+//! Nothing is submitted anywhere, this only warns about a candidate that's been rejected or is outside the known bounds.
+
+use std::{collections::BTreeMap, path::{Path, PathBuf}, str::FromStr};
+
+use serde_json::{Map, Value};
+
+use crate::{puzzle::PuzzleId, runner::Selection, Part, SolutionResult};
+
+/// What the puzzle said about a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    TooHigh,
+    TooLow,
+    /// no hint either way
+    Wrong,
+}
+
+impl std::fmt::Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow => write!(f, "too low"),
+            Feedback::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too high" => Ok(Feedback::TooHigh),
+            "too low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            _ => Err(format!("Unknown feedback: {s}")),
+        }
+    }
+}
+
+/// Why a candidate answer is probably wrong
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    Rejected(Feedback),
+    /// at or above an answer that was too high
+    TooHigh { bound: i128 },
+    /// at or below an answer that was too low
+    TooLow { bound: i128 },
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Rejected(feedback) => write!(f, "already rejected as {feedback}"),
+            Warning::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Warning::TooLow { bound } => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rejected(pub BTreeMap<(PuzzleId, Part), BTreeMap<String, Feedback>>);
+
+impl Rejected {
+    /// A missing file has nothing rejected yet
+    pub fn load(path: impl AsRef<Path>) -> SolutionResult<Self> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| crate::report(format!("Failed to read {}: {e}", path.display())))?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| crate::report(format!("Invalid rejected answers file {}: {e}", path.display())))?;
+
+        let mut rejected = Self::default();
+
+        for (id, parts) in json.as_object().into_iter().flatten() {
+            for (part, answers) in parts.as_object().into_iter().flatten() {
+                for (answer, feedback) in answers.as_object().into_iter().flatten() {
+                    let (Ok(key), Ok(part), Some(Ok(feedback))) = (id.parse(), part.parse(), feedback.as_str().map(str::parse)) else {
+                        return Err(crate::report(format!("Invalid rejected answer for {id} part {part} in {}", path.display())));
+                    };
+
+                    rejected.reject(&key, part, answer, feedback);
+                }
+            }
+        }
+
+        Ok(rejected)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> SolutionResult<()> {
+        let path = path.as_ref();
+        let mut json = Map::new();
+
+        for ((id, part), answers) in &self.0 {
+            let parts = json.entry(id.to_string()).or_insert_with(|| Value::Object(Map::new()));
+            parts[part.number().to_string()] = answers.iter()
+                .map(|(answer, feedback)| (answer.clone(), Value::String(feedback.to_string())))
+                .collect::<Map<_, _>>()
+                .into();
+        }
+
+        let text = serde_json::to_string_pretty(&Value::Object(json)).expect("json values always serialize");
+        std::fs::write(path, text + "\n")
+            .map_err(|e| crate::report(format!("Failed to write {}: {e}", path.display())))
+    }
+
+    pub fn reject(&mut self, id: &PuzzleId, part: Part, answer: &str, feedback: Feedback) {
+        self.0.entry((*id, part)).or_default().insert(answer.to_string(), feedback);
+    }
+
+    /// The highest answer that was too low and the lowest that was too high
+    pub fn bounds(&self, id: &PuzzleId, part: Part) -> (Option<i128>, Option<i128>) {
+        let answers = self.0.get(&(*id, part)).into_iter().flatten();
+        let numbers = |want: Feedback| answers.clone()
+            .filter(move |&(_, &feedback)| feedback == want)
+            .filter_map(|(answer, _)| answer.trim().parse::<i128>().ok());
+
+        (numbers(Feedback::TooLow).max(), numbers(Feedback::TooHigh).min())
+    }
+
+    pub fn check(&self, id: &PuzzleId, part: Part, answer: &str) -> Option<Warning> {
+        if let Some(&feedback) = self.0.get(&(*id, part)).and_then(|answers| answers.get(answer)) {
+            return Some(Warning::Rejected(feedback));
+        }
+
+        let answer: i128 = answer.trim().parse().ok()?;
+
+        match self.bounds(id, part) {
+            (_, Some(bound)) if answer >= bound => Some(Warning::TooHigh { bound }),
+            (Some(bound), _) if answer <= bound => Some(Warning::TooLow { bound }),
+            _ => None,
+        }
+    }
+}
+
+/// A single rejection from the command line, added to the file it names
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rejection {
+    pub path: PathBuf,
+    pub id: PuzzleId,
+    pub part: Part,
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+impl Rejection {
+    /// `YEAR DAY PART ANSWER [--too-high|--too-low] --rejected FILE`
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> SolutionResult<Self> {
+        let mut positional = Vec::new();
+        let mut feedback = Feedback::Wrong;
+        let mut path = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--too-high" => feedback = Feedback::TooHigh,
+                "--too-low" => feedback = Feedback::TooLow,
+                "--rejected" => path = Some(args.next().ok_or_else(|| crate::report("Missing file for --rejected".into()))?),
+                _ if arg.starts_with("--") => return Err(crate::report(format!("Unknown option: {arg}"))),
+                _ => positional.push(arg),
+            }
+        }
+
+        let usage = || crate::report("Usage: reject YEAR DAY PART ANSWER [--too-high|--too-low] --rejected FILE".into());
+        let (Some(path), Some(answer)) = (path, positional.pop()) else {
+            return Err(usage());
+        };
+
+        let selection = Selection::from_args(positional)?;
+        let (Some(year), Some(day), Some(part)) = (selection.year, selection.day, selection.part) else {
+            return Err(usage());
+        };
+
+        let id = if selection.everybody { PuzzleId::everybody(year, day) } else { PuzzleId::aoc(year, day) };
+
+        Ok(Self { path: path.into(), id, part, answer, feedback })
+    }
+
+    /// Add it to the file, creating the file if need be
+    pub fn save(&self) -> SolutionResult<()> {
+        let mut rejected = Rejected::load(&self.path)?;
+        rejected.reject(&self.id, self.part, &self.answer, self.feedback);
+        rejected.save(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_check() {
+        let id = PuzzleId::aoc(2015, 4);
+        let mut rejected = Rejected::default();
+        rejected.reject(&id, Part::One, "500", Feedback::TooHigh);
+        rejected.reject(&id, Part::One, "900", Feedback::TooHigh);
+        rejected.reject(&id, Part::One, "100", Feedback::TooLow);
+        rejected.reject(&id, Part::One, "250", Feedback::Wrong);
+
+        assert_eq!(rejected.bounds(&id, Part::One), (Some(100), Some(500)));
+        assert_eq!(rejected.check(&id, Part::One, "900"), Some(Warning::Rejected(Feedback::TooHigh)));
+        assert_eq!(rejected.check(&id, Part::One, "250"), Some(Warning::Rejected(Feedback::Wrong)));
+        assert_eq!(rejected.check(&id, Part::One, "600"), Some(Warning::TooHigh { bound: 500 }));
+        assert_eq!(rejected.check(&id, Part::One, "-3"), Some(Warning::TooLow { bound: 100 }));
+        assert_eq!(rejected.check(&id, Part::One, "300"), None);
+        assert_eq!(rejected.check(&id, Part::One, "abc"), None);
+        assert_eq!(rejected.check(&id, Part::Two, "900"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join("aoc-ornaments-rejected.json");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(Rejected::load(&path).unwrap(), Rejected::default());
+
+        let rejection = Rejection::from_args(args(&format!("2015 4 1 500 --too-high --rejected {}", path.display()))).unwrap();
+        assert_eq!((rejection.id, rejection.part, rejection.feedback), (PuzzleId::aoc(2015, 4), Part::One, Feedback::TooHigh));
+        rejection.save().unwrap();
+        Rejection::from_args(args(&format!("e2024 1 3 abc --rejected {}", path.display()))).unwrap().save().unwrap();
+
+        let mut expected = Rejected::default();
+        expected.reject(&PuzzleId::aoc(2015, 4), Part::One, "500", Feedback::TooHigh);
+        expected.reject(&PuzzleId::everybody(2024, 1), Part::Three, "abc", Feedback::Wrong);
+        assert_eq!(Rejected::load(&path).unwrap(), expected);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert!(Rejection::from_args(args("2015 4 1 500")).is_err());
+        assert!(Rejection::from_args(args("2015 4 500 --rejected r.json")).is_err());
+        assert!(Rejection::from_args(args("2015 4 1 500 --rejected r.json --bogus")).is_err());
+    }
+}
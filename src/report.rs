@@ -0,0 +1,139 @@
+//! A summary of a run, as a markdown table for the README or JSON for tooling
+//!
+//! Titles are whatever each day registers with [Registry::title](crate::runner::Registry::title), as the puzzle text has them.
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::{bench::Timing, puzzle::PuzzleId, verify::Verdict, Part, SolutionResult};
+
+/// A single part of a run
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub id: PuzzleId,
+    pub title: Option<String>,
+    pub part: Part,
+    /// [None] while the part is pending
+    pub answer: Option<String>,
+    pub timing: Option<Timing>,
+    /// only when answers are checked
+    pub verdict: Option<Verdict>,
+    /// why the part has no answer, when it failed rather than being pending
+    pub error: Option<String>,
+}
+
+impl Row {
+    pub fn status(&self) -> String {
+        match (&self.answer, &self.verdict) {
+            (None, _) if self.error.is_some() => "failed".into(),
+            (None, _) => "pending".into(),
+            (Some(_), Some(verdict)) => verdict.to_string(),
+            (Some(_), None) => String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report(pub Vec<Row>);
+
+impl Report {
+    pub fn push(&mut self, row: Row) {
+        self.0.push(row);
+    }
+
+    pub fn markdown(&self) -> String {
+        let mut table = String::from("| Puzzle | Title | Part | Answer | Parse | Solve | Status |\n|---|---|---|---|---|---|---|\n");
+
+        for row in &self.0 {
+            let (parse, solve) = match &row.timing {
+                Some(timing) => (format!("{:?}", timing.parse.median), format!("{:?}", timing.solve.median)),
+                None => (String::new(), String::new()),
+            };
+
+            let cells = [
+                row.id.to_string(),
+                row.title.clone().unwrap_or_default(),
+                row.part.number().to_string(),
+                row.answer.clone().unwrap_or_default(),
+                parse,
+                solve,
+                row.status(),
+            ];
+
+            table.push_str(&format!("| {} |\n", cells.map(|cell| cell.replace('|', "\\|")).join(" | ")));
+        }
+
+        table
+    }
+
+    pub fn json(&self) -> Value {
+        Value::Array(self.0.iter().map(|row| json!({
+            "puzzle": row.id.to_string(),
+            "title": row.title,
+            "part": row.part.number(),
+            "answer": row.answer,
+            "parse_ns": row.timing.map(|timing| timing.parse.median.as_nanos() as u64),
+            "solve_ns": row.timing.map(|timing| timing.solve.median.as_nanos() as u64),
+            "status": row.status(),
+            "error": row.error,
+        })).collect())
+    }
+
+    /// JSON for a `.json` file, markdown for anything else
+    pub fn save(&self, path: impl AsRef<Path>) -> SolutionResult<()> {
+        let path = path.as_ref();
+
+        let text = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::to_string_pretty(&self.json()).expect("json values always serialize") + "\n",
+            _ => self.markdown(),
+        };
+
+        std::fs::write(path, text)
+            .map_err(|e| crate::report(format!("Failed to write {}: {e}", path.display())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::Stats;
+
+    use super::*;
+
+    fn report() -> Report {
+        let stats = Stats { min: Duration::from_millis(1), median: Duration::from_millis(2), mean: Duration::from_millis(3), runs: 3 };
+
+        Report(vec![
+            Row {
+                id: PuzzleId::aoc(2015, 4),
+                title: Some("The Ideal Stocking Stuffer".into()),
+                part: Part::One,
+                answer: Some("282749".into()),
+                timing: Some(Timing { parse: stats, solve: stats }),
+                verdict: Some(Verdict::Pass),
+                error: None,
+            },
+            Row { id: PuzzleId::aoc(2015, 4), title: None, part: Part::Two, answer: None, timing: None, verdict: None, error: None },
+        ])
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(report().markdown().lines().skip(2).collect::<Vec<_>>(), vec![
+            "| 2015-12-04 | The Ideal Stocking Stuffer | 1 | 282749 | 2ms | 2ms | PASS |",
+            "| 2015-12-04 |  | 2 |  |  |  | pending |",
+        ]);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = report().json();
+
+        assert_eq!(json[0]["solve_ns"], 2_000_000);
+        assert_eq!(json[0]["status"], "PASS");
+        assert_eq!(json[1]["answer"], Value::Null);
+        assert_eq!(json[1]["status"], "pending");
+    }
+}
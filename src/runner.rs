@@ -0,0 +1,920 @@
+//! Registry of solutions and a runner for one day, one part, a whole year, or everything
+
+use std::{collections::BTreeMap, panic::{self, AssertUnwindSafe}, path::PathBuf, str::FromStr, sync::{atomic::{AtomicUsize, Ordering}, mpsc, Once}, thread, time::{Duration, Instant}};
+
+use crate::{bench::{Bench, Change, Results, Timing}, error::is_todo, input::Input, puzzle::PuzzleId, rejected::Rejected, report::{Report, Row}, verify::{Answers, Verdict}, ArgSolution, DefaultArgs, Notes, Part, Solution, SolutionError, SolutionReport, SolutionResult};
+
+/// Solves a part of an already parsed puzzle, which may borrow from the input
+pub type Parsed<'a> = Box<dyn FnMut(Part) -> SolutionResult<String> + 'a>;
+
+/// Parses the input, kept separate from solving so each can be timed
+pub type Solver = Box<dyn for<'a> Fn(&'a str) -> SolutionResult<Parsed<'a>> + Send + Sync>;
+
+/// Wraps a solution parsed with [Parse](crate::Parse), for registering one that borrows from its input
+///
+/// ```ignore
+/// registry.insert(PuzzleId::aoc(2015, 23), Part::One, |input| runner::borrowed(Day::parse(input)));
+/// ```
+pub fn borrowed<'a, D, E>(parsed: Result<D, E>) -> SolutionResult<Parsed<'a>>
+where
+    D: Solution + 'a,
+    E: Into<SolutionReport>,
+{
+    let mut day = parsed.map_err(Into::into)?;
+
+    Ok(Box::new(move |part| day.solve(part)))
+}
+
+/// All known solutions, by puzzle and part
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<PuzzleId, BTreeMap<Part, Solver>>,
+    /// for reports, when the puzzle was given one
+    titles: BTreeMap<PuzzleId, String>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register every part of the puzzle
+    pub fn solution<D>(&mut self, id: PuzzleId) -> &mut Self
+    where
+        D: Solution + FromStr + 'static,
+        D::Err: Into<SolutionReport>,
+    {
+        for part in id.parts() {
+            self.solution_part::<D>(id, *part);
+        }
+
+        self
+    }
+
+    /// Register a single part, useful when a solution only implements some of them
+    pub fn solution_part<D>(&mut self, id: PuzzleId, part: Part) -> &mut Self
+    where
+        D: Solution + FromStr + 'static,
+        D::Err: Into<SolutionReport>,
+    {
+        self.insert(id, part, |input| borrowed(input.parse::<D>()))
+    }
+
+    /// Register a single part with the argument it needs for the real input
+    pub fn arg_solution_part<D, A>(&mut self, id: PuzzleId, part: Part, args: A) -> &mut Self
+    where
+        D: ArgSolution<A> + FromStr + 'static,
+        D::Err: Into<SolutionReport>,
+        A: Clone + Send + Sync + 'static,
+    {
+        self.insert(id, part, move |input| {
+            let mut day = input.parse::<D>().map_err(Into::into)?;
+            let args = args.clone();
+            Ok(Box::new(move |part| day.solve(part, args.clone())))
+        })
+    }
+
+    /// Register every part with the arguments it declares for the real input
+    pub fn arg_solution<D, A>(&mut self, id: PuzzleId) -> &mut Self
+    where
+        D: DefaultArgs<A> + FromStr + 'static,
+        D::Err: Into<SolutionReport>,
+        A: Clone + Send + Sync + 'static,
+    {
+        for &part in id.parts() {
+            self.arg_solution_part::<D, A>(id, part, D::args(part));
+        }
+
+        self
+    }
+
+    /// Register every part of a puzzle whose input is only the argument, solved against `data` that never changes, like a shop's price list
+    pub fn input_arg_solution<D, A>(&mut self, id: PuzzleId, data: &'static str) -> &mut Self
+    where
+        D: ArgSolution<A> + FromStr + 'static,
+        D::Err: Into<SolutionReport>,
+        A: FromStr + Clone + 'static,
+        A::Err: Into<SolutionReport>,
+    {
+        for &part in id.parts() {
+            self.insert(id, part, move |input| {
+                let args = input.parse::<A>().map_err(Into::into)?;
+                let mut day = data.parse::<D>().map_err(Into::into)?;
+                Ok(Box::new(move |part| day.solve(part, args.clone())))
+            });
+        }
+
+        self
+    }
+
+    /// Register every part of an everybody.codes quest, each parsed from its own notes
+    pub fn quest<D>(&mut self, id: PuzzleId) -> &mut Self
+    where
+        D: Solution + for<'a> Notes<'a> + 'static,
+    {
+        for &part in id.parts() {
+            self.insert(id, part, move |notes| borrowed(D::parse_notes(notes, part)));
+        }
+
+        self
+    }
+
+    /// Register anything that can parse an input into something that answers, see [borrowed]
+    pub fn insert<F>(&mut self, id: PuzzleId, part: Part, solver: F) -> &mut Self
+    where
+        F: for<'a> Fn(&'a str) -> SolutionResult<Parsed<'a>> + Send + Sync + 'static,
+    {
+        self.solvers.entry(id).or_default().insert(part, Box::new(solver));
+
+        self
+    }
+
+    /// Name the puzzle as the puzzle text does, for the [report](crate::report)
+    pub fn title(&mut self, id: PuzzleId, title: &str) -> &mut Self {
+        self.titles.insert(id, title.to_string());
+
+        self
+    }
+
+    pub fn get(&self, id: &PuzzleId, part: Part) -> Option<&Solver> {
+        self.solvers.get(id)?.get(&part)
+    }
+
+    pub fn get_title(&self, id: &PuzzleId) -> Option<&str> {
+        self.titles.get(id).map(String::as_str)
+    }
+
+    /// Every registered puzzle and part matching the selection, in order
+    pub fn select<'a>(&'a self, selection: &'a Selection) -> impl Iterator<Item = (PuzzleId, Part, &'a Solver)> + 'a {
+        self.solvers.iter()
+            .filter(|(id, _)| selection.matches(id))
+            .flat_map(move |(id, parts)| parts.iter()
+                .filter(|(part, _)| selection.part.is_none_or(|p| p == **part))
+                .map(|(part, solver)| (*id, *part, solver)))
+    }
+}
+
+/// Which puzzles to run, narrowed down from everything
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Selection {
+    pub everybody: bool,
+    /// AoC year or everybody.codes event
+    pub year: Option<u16>,
+    /// AoC day or everybody.codes quest
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+}
+
+impl Selection {
+    /// `[YEAR [DAY [PART]]]`, prefix the year with `e` for an everybody.codes event: `e2024 1 3`
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> SolutionResult<Self> {
+        let mut selection = Self::default();
+        let mut args = args.into_iter();
+
+        if let Some(year) = args.next() {
+            let year = match year.strip_prefix('e') {
+                Some(event) => {
+                    selection.everybody = true;
+                    event.to_string()
+                },
+                None => year,
+            };
+
+            selection.year = Some(year.parse().map_err(|_| crate::report(format!("Invalid year: {year}")))?);
+        }
+
+        if let Some(day) = args.next() {
+            selection.day = Some(day.parse().map_err(|_| crate::report(format!("Invalid day: {day}")))?);
+        }
+
+        if let Some(part) = args.next() {
+            selection.part = Some(part.parse()?);
+        }
+
+        if let Some(extra) = args.next() {
+            return Err(crate::report(format!("Unexpected argument: {extra}")));
+        }
+
+        Ok(selection)
+    }
+
+    pub fn matches(&self, id: &PuzzleId) -> bool {
+        let (everybody, year, day) = match *id {
+            PuzzleId::Aoc { year, day } => (false, year, day),
+            PuzzleId::Everybody { event, quest } => (true, event, quest),
+        };
+
+        if self.year.is_none() {
+            return true;
+        }
+
+        self.everybody == everybody
+            && self.year.is_none_or(|y| y == year)
+            && self.day.is_none_or(|d| d == day)
+    }
+}
+
+/// Everything from the command line
+///
+/// `[YEAR [DAY [PART]]] [--input FILE|-] [--inputs DIR] [--bench RUNS] [--warmup RUNS] [--save FILE] [--baseline FILE] [--answers FILE [--record]] [--rejected FILE] [--report FILE] [--jobs N] [--timeout SECS]`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Args {
+    pub selection: Selection,
+    /// defaults to [Input::default]
+    pub input: Option<Input>,
+    pub bench: Bench,
+    /// save the timings as JSON
+    pub save: Option<PathBuf>,
+    /// compare the timings to a previous save
+    pub baseline: Option<PathBuf>,
+    /// verify answers against the accepted ones
+    pub answers: Option<PathBuf>,
+    /// accept any NEW answers
+    pub record: bool,
+    /// warn about answers that were already turned down
+    pub rejected: Option<PathBuf>,
+    /// threads to run parts on, one per CPU when zero
+    pub jobs: Option<usize>,
+    /// budget for each part
+    pub timeout: Option<Duration>,
+    /// summarise the run as markdown, or JSON for a `.json` file
+    pub report: Option<PathBuf>,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> SolutionResult<Self> {
+        let mut parsed = Self::default();
+        let mut positional = Vec::new();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| crate::report(format!("Missing value for {arg}")));
+
+            match arg.as_str() {
+                "--input" => parsed.input = Some(match value()?.as_str() {
+                    "-" => Input::Stdin,
+                    path => Input::Path(path.into()),
+                }),
+                "--inputs" => parsed.input = Some(Input::dir(value()?)),
+                "--bench" => parsed.bench.runs = number(&arg, value()?)?.max(1),
+                "--warmup" => parsed.bench.warmup = number(&arg, value()?)?,
+                "--save" => parsed.save = Some(value()?.into()),
+                "--baseline" => parsed.baseline = Some(value()?.into()),
+                "--answers" => parsed.answers = Some(value()?.into()),
+                "--record" => parsed.record = true,
+                "--rejected" => parsed.rejected = Some(value()?.into()),
+                "--jobs" | "-j" => parsed.jobs = Some(number(&arg, value()?)?),
+                "--report" => parsed.report = Some(value()?.into()),
+                "--timeout" => parsed.timeout = Some(seconds(&arg, value()?)?),
+                _ if arg.starts_with("--") => return Err(crate::report(format!("Unknown option: {arg}"))),
+                _ => positional.push(arg),
+            }
+        }
+
+        if parsed.record && parsed.answers.is_none() {
+            return Err(crate::report("--record needs --answers FILE".into()));
+        }
+
+        parsed.selection = Selection::from_args(positional)?;
+
+        Ok(parsed)
+    }
+
+    pub fn runner(&self, registry: Registry) -> SolutionResult<Runner> {
+        let mut runner = Runner::new(registry).bench(self.bench);
+
+        if let Some(jobs) = self.jobs {
+            let cpus = || thread::available_parallelism().map_or(1, Into::into);
+            runner = runner.jobs(if jobs == 0 { cpus() } else { jobs });
+        }
+
+        if let Some(input) = &self.input {
+            runner = runner.input(input.clone());
+        }
+
+        if let Some(path) = &self.save {
+            runner = runner.save(path);
+        }
+
+        if let Some(path) = &self.baseline {
+            runner = runner.baseline(Results::load(path)?);
+        }
+
+        if let Some(path) = &self.answers {
+            runner = runner.answers(path, self.record);
+        }
+
+        if let Some(path) = &self.rejected {
+            runner = runner.rejected(Rejected::load(path)?);
+        }
+
+        if let Some(path) = &self.report {
+            runner = runner.report(path);
+        }
+
+        if let Some(timeout) = self.timeout {
+            runner = runner.timeout(timeout);
+        }
+
+        Ok(runner)
+    }
+}
+
+fn number(arg: &str, value: String) -> SolutionResult<usize> {
+    value.parse().map_err(|_| crate::report(format!("Invalid number for {arg}: {value}")))
+}
+
+fn seconds(arg: &str, value: String) -> SolutionResult<Duration> {
+    value.parse().ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| crate::report(format!("Invalid number of seconds for {arg}: {value}")))
+}
+
+/// Runs the selected solutions against their inputs and prints the answers
+pub struct Runner {
+    registry: Registry,
+    input: Input,
+    bench: Bench,
+    save: Option<PathBuf>,
+    baseline: Option<Results>,
+    answers: Option<PathBuf>,
+    record: bool,
+    rejected: Option<Rejected>,
+    jobs: usize,
+    report: Option<PathBuf>,
+    timeout: Option<Duration>,
+}
+
+impl Runner {
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            registry,
+            input: Input::default(),
+            bench: Bench::default(),
+            save: None,
+            baseline: None,
+            answers: None,
+            record: false,
+            rejected: None,
+            jobs: 1,
+            report: None,
+            timeout: None,
+        }
+    }
+
+    pub fn input(mut self, input: Input) -> Self {
+        self.input = input;
+        self
+    }
+
+    pub fn bench(mut self, bench: Bench) -> Self {
+        self.bench = bench;
+        self
+    }
+
+    pub fn save(mut self, path: impl Into<PathBuf>) -> Self {
+        self.save = Some(path.into());
+        self
+    }
+
+    pub fn baseline(mut self, baseline: Results) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Check answers against the accepted ones in the file, `record` accepts the NEW ones
+    pub fn answers(mut self, path: impl Into<PathBuf>, record: bool) -> Self {
+        self.answers = Some(path.into());
+        self.record = record;
+        self
+    }
+
+    /// Warn about answers that were already turned down, or are outside their bounds
+    pub fn rejected(mut self, rejected: Rejected) -> Self {
+        self.rejected = Some(rejected);
+        self
+    }
+
+    /// Summarise the run as markdown, or JSON for a `.json` file, see [report]
+    pub fn report(mut self, path: impl Into<PathBuf>) -> Self {
+        self.report = Some(path.into());
+        self
+    }
+
+    /// Fail any part that takes longer to parse and solve, each bench run gets the whole budget, see [cancel](crate::cancel)
+    pub fn timeout(mut self, budget: Duration) -> Self {
+        self.timeout = Some(budget);
+        self
+    }
+
+    /// Run independent parts on this many threads, the output stays in order
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs.max(1);
+        self
+    }
+
+    /// Unimplemented parts, whether [SolutionError::Unimplemented] or a `todo!()`, are shown as pending rather than stopping the run
+    pub fn run(&self, selection: &Selection) -> SolutionResult<()> {
+        quiet_todo_panics();
+
+        let started = Instant::now();
+        let work: Vec<_> = self.registry.select(selection).collect();
+        let mut progress = Progress {
+            answers: match &self.answers {
+                Some(path) => Some(Answers::load(path)?),
+                None => None,
+            },
+            ..Default::default()
+        };
+
+        if self.jobs > 1 && work.len() > 1 {
+            self.run_parallel(&work, &mut progress);
+        } else {
+            for &(id, part, solver) in &work {
+                self.record(&mut progress, id, part, self.solve(&id, part, solver));
+            }
+        }
+
+        if work.is_empty() {
+            println!("Nothing registered for {selection:?}");
+        }
+
+        if progress.pending > 0 {
+            println!("{} part(s) pending", progress.pending);
+        }
+
+        if !work.is_empty() {
+            println!("Total: {:?} elapsed, {:?} summed over parts", started.elapsed(), progress.busy);
+        }
+
+        if let Some(path) = &self.save {
+            progress.results.save(path)?;
+        }
+
+        if let (Some(path), Some(answers), true) = (&self.answers, &progress.answers, self.record) {
+            answers.save(path)?;
+        }
+
+        if let Some(path) = &self.report {
+            progress.report.save(path)?;
+        }
+
+        // only once everything is saved, the parts that did finish shouldn't be lost with it
+        if progress.errors.len() > 1 {
+            return Err(crate::report(format!("{} part(s) failed, see above", progress.errors.len())));
+        }
+
+        if let Some(e) = progress.errors.pop() {
+            return Err(e);
+        }
+
+        if progress.failed > 0 {
+            return Err(crate::report(format!("{} answer(s) failed verification", progress.failed)));
+        }
+
+        Ok(())
+    }
+
+    /// Workers take the next part as they finish, the results are printed in selection order
+    fn run_parallel(&self, work: &[(PuzzleId, Part, &Solver)], progress: &mut Progress) {
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..self.jobs.min(work.len()) {
+                let (sender, next) = (sender.clone(), &next);
+
+                scope.spawn(move || {
+                    while let Some(&(id, part, solver)) = work.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        if sender.send((id, part, self.solve(&id, part, solver))).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut finished = BTreeMap::new();
+            let mut printed = 0;
+
+            for (id, part, outcome) in receiver {
+                finished.insert((id, part), outcome);
+
+                while let Some(outcome) = work.get(printed).and_then(|&(id, part, _)| finished.remove(&(id, part))) {
+                    let (id, part, _) = work[printed];
+                    printed += 1;
+
+                    self.record(progress, id, part, outcome);
+                }
+            }
+        })
+    }
+
+    /// Load the input and measure a single part, safe to call from any thread
+    fn solve(&self, id: &PuzzleId, part: Part, solver: &Solver) -> Outcome {
+        let input = match self.input.load(id, part) {
+            Ok(input) => input,
+            Err(e) => return Outcome::Failed(e),
+        };
+
+        let started = Instant::now();
+        let measured = panic::catch_unwind(AssertUnwindSafe(|| self.bench.measure(solver, &input, part, self.timeout)));
+
+        match measured {
+            Ok(Ok((answer, timing))) => Outcome::Solved { answer, timing, elapsed: started.elapsed() },
+            Ok(Err(e)) if !SolutionError::is_unimplemented(&e) => Outcome::Failed(e),
+            Err(payload) if !is_todo(&*payload) => panic::resume_unwind(payload),
+            _ => Outcome::Pending,
+        }
+    }
+
+    /// Print a part's outcome, a real failure is kept for the end of the run so the other parts still get their turn
+    fn record(&self, progress: &mut Progress, id: PuzzleId, part: Part, outcome: Outcome) {
+        if progress.current != Some(id) {
+            println!("{id}");
+            progress.current = Some(id);
+            progress.title = self.registry.get_title(&id).map(String::from);
+        }
+
+        let mut row = Row { id, title: progress.title.clone(), part, answer: None, timing: None, verdict: None, error: None };
+
+        let (answer, timing, elapsed) = match outcome {
+            Outcome::Solved { answer, timing, elapsed } => (answer, timing, elapsed),
+            Outcome::Pending => {
+                println!("  {part}: pending");
+                progress.pending += 1;
+                progress.report.push(row);
+                return;
+            },
+            Outcome::Failed(e) => {
+                println!("  {part}: failed ({e})");
+                progress.report.push(Row { error: Some(e.to_string()), ..row });
+                progress.errors.push(e);
+                return;
+            },
+        };
+
+        // on the answer's line, so it can't be missed or mistaken for the next part's
+        let warning = self.rejected.as_ref()
+            .and_then(|rejected| rejected.check(&id, part, &answer))
+            .map_or_else(String::new, |warning| format!(" (warning: {warning})"));
+
+        match progress.answers.as_mut() {
+            Some(answers) => {
+                let verdict = answers.check(&id, part, &answer);
+                println!("  {part}: {answer} ({verdict}){warning}");
+
+                match verdict {
+                    Verdict::Fail { .. } => progress.failed += 1,
+                    Verdict::New if self.record => answers.record(&id, part, &answer),
+                    _ => (),
+                }
+
+                row.verdict = Some(verdict);
+            },
+            None => println!("  {part}: {answer}{warning}"),
+        }
+
+        match self.baseline.as_ref().and_then(|baseline| baseline.get(&id, part)) {
+            Some(baseline) => println!("    {timing} ({})", Change::between(baseline, &timing)),
+            None => println!("    {timing}"),
+        }
+
+        progress.results.insert(&id, part, timing);
+        progress.busy += elapsed;
+        progress.report.push(Row { answer: Some(answer), timing: Some(timing), ..row });
+    }
+}
+
+/// What running a single part came to
+enum Outcome {
+    Solved { answer: String, timing: Timing, elapsed: Duration },
+    Pending,
+    Failed(SolutionReport),
+}
+
+/// Everything [Runner::run] keeps track of while printing
+#[derive(Default)]
+struct Progress {
+    current: Option<PuzzleId>,
+    results: Results,
+    answers: Option<Answers>,
+    failed: usize,
+    /// parts that returned an error rather than an answer
+    errors: Vec<SolutionReport>,
+    pending: usize,
+    /// time spent measuring, summed over every part
+    busy: Duration,
+    /// of the current puzzle
+    title: Option<String>,
+    report: Report,
+}
+
+/// `todo!()` panics are shown as pending, so don't print them as well
+fn quiet_todo_panics() {
+    static QUIET: Once = Once::new();
+
+    QUIET.call_once(|| {
+        let previous = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if !is_todo(info.payload()) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_selection_from_args() {
+        assert_eq!(Selection::from_args(args("")).unwrap(), Selection::default());
+        assert_eq!(Selection::from_args(args("2015 4 2")).unwrap(), Selection { everybody: false, year: Some(2015), day: Some(4), part: Some(Part::Two) });
+        assert_eq!(Selection::from_args(args("e2024 1")).unwrap(), Selection { everybody: true, year: Some(2024), day: Some(1), part: None });
+        assert!(Selection::from_args(args("2015 4 4")).is_err());
+        assert!(Selection::from_args(args("twenty")).is_err());
+    }
+
+    #[test]
+    fn test_args() {
+        let parsed = Args::parse(args("2015 4 --input -")).unwrap();
+        assert_eq!(parsed.selection.day, Some(4));
+        assert_eq!(parsed.input, Some(Input::Stdin));
+
+        let parsed = Args::parse(args("--inputs elsewhere")).unwrap();
+        assert_eq!(parsed.selection, Selection::default());
+        assert_eq!(parsed.input, Some(Input::dir("elsewhere")));
+
+        let parsed = Args::parse(args("--bench 10 --warmup 2 --save now.json")).unwrap();
+        assert_eq!(parsed.bench, Bench::new(2, 10));
+        assert_eq!(parsed.save, Some("now.json".into()));
+
+        let parsed = Args::parse(args("--answers answers.json --record")).unwrap();
+        assert_eq!(parsed.answers, Some("answers.json".into()));
+        assert!(parsed.record);
+
+        let parsed = Args::parse(args("2015 4 --rejected rejected.json")).unwrap();
+        assert_eq!(parsed.rejected, Some("rejected.json".into()));
+
+        let parsed = Args::parse(args("--timeout 1.5")).unwrap();
+        assert_eq!(parsed.timeout, Some(Duration::from_millis(1500)));
+        assert!(Args::parse(args("--timeout 0")).is_err());
+
+        let parsed = Args::parse(args("2015 -j 4")).unwrap();
+        assert_eq!(parsed.jobs, Some(4));
+
+        assert!(Args::parse(args("--record")).is_err());
+        assert!(Args::parse(args("--bench ten")).is_err());
+        assert!(Args::parse(args("--input")).is_err());
+        assert!(Args::parse(args("--bogus")).is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let mut registry = Registry::new();
+        registry.insert(PuzzleId::aoc(2015, 2), Part::One, |_| Ok(Box::new(|_| Ok("1".into()))))
+            .insert(PuzzleId::aoc(2015, 1), Part::Two, |_| Ok(Box::new(|_| Ok("2".into()))))
+            .insert(PuzzleId::aoc(2015, 1), Part::One, |_| Ok(Box::new(|_| Ok("1".into()))))
+            .insert(PuzzleId::everybody(2024, 1), Part::Three, |_| Ok(Box::new(|_| Ok("3".into()))))
+            .title(PuzzleId::aoc(2015, 2), "I Was Told There Would Be No Math");
+
+        assert_eq!(registry.get_title(&PuzzleId::aoc(2015, 2)), Some("I Was Told There Would Be No Math"));
+        assert_eq!(registry.get_title(&PuzzleId::aoc(2015, 1)), None);
+
+        let everything = Selection::default();
+        let selected: Vec<_> = registry.select(&everything).map(|(id, part, _)| (id, part)).collect();
+        assert_eq!(selected, vec![
+            (PuzzleId::aoc(2015, 1), Part::One),
+            (PuzzleId::aoc(2015, 1), Part::Two),
+            (PuzzleId::aoc(2015, 2), Part::One),
+            (PuzzleId::everybody(2024, 1), Part::Three),
+        ]);
+
+        let year = Selection::from_args(args("2015")).unwrap();
+        assert_eq!(registry.select(&year).count(), 3);
+
+        let part = Selection::from_args(args("2015 1 2")).unwrap();
+        let (_, _, solver) = registry.select(&part).next().unwrap();
+        assert_eq!(solver("").unwrap()(Part::Two).unwrap(), "2");
+
+        let quest = Selection::from_args(args("e2024 1")).unwrap();
+        assert_eq!(registry.select(&quest).count(), 1);
+    }
+
+    #[test]
+    fn test_pending() {
+        let mut registry = Registry::new();
+        registry.insert(PuzzleId::aoc(2015, 1), Part::One, |_| Ok(Box::new(|_| Ok("1".into()))))
+            .insert(PuzzleId::aoc(2015, 1), Part::Two, |_| Ok(Box::new(|part| Err(SolutionError::Unimplemented(part).into()))))
+            .insert(PuzzleId::aoc(2015, 2), Part::One, |_| Ok(Box::new(|_| todo!())))
+            .insert(PuzzleId::aoc(2015, 2), Part::Two, |_| Err(crate::report("broken".into())));
+
+        let path = std::env::temp_dir().join("aoc-ornaments-pending.txt");
+        std::fs::write(&path, "").unwrap();
+        let runner = Runner::new(registry).input(Input::Path(path.clone()));
+        let first = Selection::from_args(args("2015 1")).unwrap();
+        assert!(runner.run(&first).is_ok());
+
+        let todo = Selection::from_args(args("2015 2 1")).unwrap();
+        assert!(runner.run(&todo).is_ok());
+
+        let broken = Selection::from_args(args("2015 2 2")).unwrap();
+        assert!(runner.run(&broken).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_failed() {
+        let mut registry = Registry::new();
+        registry.insert(PuzzleId::aoc(2015, 1), Part::One, |_| Err(crate::report("broken".into())))
+            .insert(PuzzleId::aoc(2015, 1), Part::Two, |_| Ok(Box::new(|_| Ok("2".into()))));
+
+        let input = std::env::temp_dir().join("aoc-ornaments-failed.txt");
+        let report = std::env::temp_dir().join("aoc-ornaments-failed.json");
+        std::fs::write(&input, "").unwrap();
+
+        // the part after the broken one still runs, and the report is still written
+        let runner = Runner::new(registry).input(Input::Path(input.clone())).report(&report);
+        assert_eq!(runner.run(&Selection::default()).unwrap_err().to_string(), "broken");
+
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+        assert_eq!(json[0]["status"], "failed");
+        assert_eq!(json[0]["error"], "broken");
+        assert_eq!(json[1]["answer"], "2");
+
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(report).unwrap();
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut registry = Registry::new();
+        registry.insert(PuzzleId::aoc(2015, 1), Part::One, |_| Ok(Box::new(|_| loop {
+                crate::cancel::checkpoint()?;
+            })))
+            .insert(PuzzleId::aoc(2015, 1), Part::Two, |_| Ok(Box::new(|_| {
+                // never checks, but is still late
+                thread::sleep(Duration::from_millis(300));
+                Ok("2".into())
+            })))
+            .insert(PuzzleId::aoc(2015, 2), Part::One, |_| Ok(Box::new(|_| Ok("1".into()))))
+            .insert(PuzzleId::aoc(2015, 3), Part::One, |_| Ok(Box::new(|_| {
+                // over budget in total, but not in any one run
+                thread::sleep(Duration::from_millis(30));
+                Ok("3".into())
+            })));
+
+        let path = std::env::temp_dir().join("aoc-ornaments-timeout.txt");
+        std::fs::write(&path, "").unwrap();
+        let runner = Runner::new(registry).input(Input::Path(path.clone())).bench(Bench::new(1, 5)).timeout(Duration::from_millis(100));
+
+        for (selection, timed_out) in [("2015 1 1", true), ("2015 1 2", true), ("2015 2", false), ("2015 3", false)] {
+            let result = runner.run(&Selection::from_args(args(selection)).unwrap());
+            let timeout = result.err().and_then(|e| e.downcast_ref::<SolutionError>().cloned());
+
+            assert_eq!(timeout == Some(SolutionError::TimedOut(Duration::from_millis(100))), timed_out, "{selection}");
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_parallel() {
+        let mut registry = Registry::new();
+
+        for day in 1..=4u8 {
+            registry.insert(PuzzleId::aoc(2015, day), Part::One, move |_| Ok(Box::new(move |_| {
+                // the first days finish last
+                thread::sleep(Duration::from_millis(40 - 10 * day as u64));
+                Ok(day.to_string())
+            })));
+        }
+
+        let input = std::env::temp_dir().join("aoc-ornaments-parallel.txt");
+        let answers = std::env::temp_dir().join("aoc-ornaments-parallel.json");
+        std::fs::write(&input, "").unwrap();
+        let _ = std::fs::remove_file(&answers);
+
+        let runner = Runner::new(registry).input(Input::Path(input.clone())).jobs(4).answers(&answers, true);
+        runner.run(&Selection::default()).unwrap();
+
+        let recorded = Answers::load(&answers).unwrap();
+        for day in 1..=4u8 {
+            assert_eq!(recorded.get(&PuzzleId::aoc(2015, day), Part::One), Some(&day.to_string()));
+        }
+
+        std::fs::remove_file(input).unwrap();
+        std::fs::remove_file(answers).unwrap();
+    }
+
+    #[test]
+    fn test_input_arg_solution() -> SolutionResult<()> {
+        struct Shop(usize);
+
+        #[derive(Clone)]
+        struct Gold(usize);
+
+        impl FromStr for Gold {
+            type Err = SolutionReport;
+
+            fn from_str(input: &str) -> SolutionResult<Self> {
+                input.parse().map(Self).map_err(|_| crate::report(format!("Invalid gold: {input}")))
+            }
+        }
+
+        impl FromStr for Shop {
+            type Err = SolutionReport;
+
+            fn from_str(prices: &str) -> SolutionResult<Self> {
+                Ok(Self(prices.lines().count()))
+            }
+        }
+
+        impl ArgSolution<Gold> for Shop {
+            type Output = usize;
+
+            fn part1(&mut self, gold: Gold) -> SolutionResult<Self::Output> {
+                Ok(gold.0 / self.0)
+            }
+        }
+
+        let id = PuzzleId::aoc(2015, 21);
+        let mut registry = Registry::new();
+        registry.input_arg_solution::<Shop, Gold>(id, "sword\nshield");
+
+        assert_eq!(registry.select(&Selection::default()).count(), 2);
+        assert_eq!(registry.get(&id, Part::One).unwrap()("10")?(Part::One)?, "5");
+        assert!(registry.get(&id, Part::One).unwrap()("ten").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quest() -> SolutionResult<()> {
+        struct Quest(Part, usize);
+
+        impl Notes<'_> for Quest {
+            fn parse_notes(notes: &str, part: Part) -> SolutionResult<Self> {
+                Ok(Self(part, notes.len()))
+            }
+        }
+
+        impl Solution for Quest {
+            type Output = String;
+
+            fn part1(&mut self) -> SolutionResult<Self::Output> {
+                Ok(format!("{} {}", self.0, self.1))
+            }
+
+            fn part3(&mut self) -> SolutionResult<Self::Output> {
+                Ok(format!("{} {}", self.0, self.1))
+            }
+        }
+
+        let id = PuzzleId::everybody(2024, 1);
+        let mut registry = Registry::new();
+        registry.quest::<Quest>(id);
+
+        assert_eq!(registry.select(&Selection::default()).count(), 3);
+        assert_eq!(registry.get(&id, Part::One).unwrap()("a")?(Part::One)?, "Part 1 1");
+        assert_eq!(registry.get(&id, Part::Three).unwrap()("abc")?(Part::Three)?, "Part 3 3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_borrowed() {
+        struct Day<'a>(Vec<&'a str>);
+
+        impl Solution for Day<'_> {
+            type Output = String;
+
+            fn part1(&mut self) -> SolutionResult<Self::Output> {
+                Ok(self.0.concat())
+            }
+        }
+
+        impl<'a> crate::Parse<'a> for Day<'a> {
+            type Err = SolutionReport;
+
+            fn parse(input: &'a str) -> SolutionResult<Self> {
+                Ok(Self(input.split(',').collect()))
+            }
+        }
+
+        let mut registry = Registry::new();
+        registry.insert(PuzzleId::aoc(2015, 1), Part::One, |input| borrowed(<Day as crate::Parse>::parse(input)));
+
+        let solver = registry.get(&PuzzleId::aoc(2015, 1), Part::One).unwrap();
+        assert_eq!(solver("a,b,c").unwrap()(Part::One).unwrap(), "abc");
+    }
+}
@@ -0,0 +1,311 @@
+//! Start a new day from a template, rather than copying the last one
+//!
+//! ```sh
+//! cargo run -- new 2015 6               # Solution
+//! cargo run -- new 2015 7 --args u32    # ArgSolution<u32>
+//! cargo run -- new e2024 1              # everybody.codes, parsing each part's notes
+//! cargo run -- new 2015 6 --page day6.html
+//! ```
+//!
+//! Creates the day, its examples and empty inputs, then registers it with the runner. Existing files are never overwritten.
+//!
+//! The examples are filled in from a saved puzzle page, either `--page FILE` or `src/inputs/2015-12-06.html` when it's there.
+//! See [page](crate::page) for how they're picked. An [ArgSolution](crate::ArgSolution)'s examples use its `example_args` unless a header says ` with ARG`.
+
+use std::path::{Path, PathBuf};
+
+use crate::{example::Example, page, puzzle::PuzzleId, runner::Selection, SolutionResult};
+
+/// Where days live, relative to the crate root
+pub const DAYS: &str = "src/bin/aoc";
+
+/// Where new, empty inputs go, relative to the crate root
+pub const INPUTS: &str = "src/inputs";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scaffold {
+    pub id: PuzzleId,
+    /// the argument's type, for an [ArgSolution](crate::ArgSolution)
+    pub args: Option<String>,
+    /// from the puzzle page
+    pub examples: Vec<Example>,
+    /// where the day goes, relative to the crate root, [DAYS] unless it's somewhere else
+    pub days: String,
+}
+
+impl Scaffold {
+    pub fn new(id: PuzzleId) -> Self {
+        Self { id, args: None, examples: Vec::new(), days: DAYS.to_string() }
+    }
+
+    pub fn with_args(mut self, ty: impl ToString) -> Self {
+        self.args = Some(ty.to_string());
+        self
+    }
+
+    /// Put the day, its examples and the `main.rs` it's registered in somewhere other than [DAYS]
+    pub fn with_days(mut self, dir: impl ToString) -> Self {
+        self.days = dir.to_string();
+        self
+    }
+
+    /// Fill in the examples from a saved puzzle page
+    pub fn with_page(mut self, html: &str) -> Self {
+        self.examples = page::examples(html);
+        self
+    }
+
+    /// Where a saved puzzle page is looked for, relative to the crate root
+    pub fn page(&self) -> PathBuf {
+        Path::new(INPUTS).join(format!("{}.html", self.id))
+    }
+
+    /// `YEAR DAY [--args TYPE] [--page FILE]`, prefix the year with `e` for an everybody.codes event
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> SolutionResult<Self> {
+        let mut positional = Vec::new();
+        let mut ty = None;
+        let mut page = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--args" => ty = Some(args.next().ok_or_else(|| crate::report("Missing type for --args".into()))?),
+                "--page" => page = Some(args.next().ok_or_else(|| crate::report("Missing file for --page".into()))?),
+                _ if arg.starts_with("--") => return Err(crate::report(format!("Unknown option: {arg}"))),
+                _ => positional.push(arg),
+            }
+        }
+
+        let selection = Selection::from_args(positional)?;
+        let (Some(year), Some(day), None) = (selection.year, selection.day, selection.part) else {
+            return Err(crate::report("Usage: new YEAR DAY [--args TYPE] [--page FILE]".into()));
+        };
+
+        let id = if selection.everybody { PuzzleId::everybody(year, day) } else { PuzzleId::aoc(year, day) };
+        if selection.everybody && ty.is_some() {
+            return Err(crate::report("--args is only for Advent of Code days, everybody.codes quests parse each part's notes instead".into()));
+        }
+
+        let mut scaffold = Self { args: ty, ..Self::new(id) };
+
+        if let Some(path) = page {
+            let html = std::fs::read_to_string(&path)
+                .map_err(|e| crate::report(format!("Failed to read {path}: {e}")))?;
+            scaffold = scaffold.with_page(&html);
+        }
+
+        Ok(scaffold)
+    }
+
+    /// The module name the runner knows the day by
+    pub fn module(&self) -> String {
+        match self.id {
+            PuzzleId::Aoc { .. } => format!("day_{}", self.id.to_string().replace('-', "_")),
+            PuzzleId::Everybody { .. } => self.id.to_string(),
+        }
+    }
+
+    /// Every file to create, relative to the crate root
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        let mut files = vec![
+            (Path::new(&self.days).join(format!("{}.rs", self.id)), self.source()),
+            (Path::new(&self.days).join(format!("{}.examples", self.id)), Example::sidecar(&self.examples)),
+        ];
+
+        let inputs = match self.id {
+            PuzzleId::Aoc { .. } => vec![format!("{}.txt", self.id)],
+            PuzzleId::Everybody { .. } => self.id.parts().iter().map(|part| format!("{}_p{}.txt", self.id, part.number())).collect(),
+        };
+        files.extend(inputs.into_iter().map(|name| (Path::new(INPUTS).join(name), String::new())));
+
+        files
+    }
+
+    /// Write any files that don't exist yet and register the day, returns what was created
+    ///
+    /// Without examples, they come from the saved [page](Self::page) if there is one.
+    pub fn create(&self, root: impl AsRef<Path>) -> SolutionResult<Vec<PathBuf>> {
+        let root = root.as_ref();
+        let mut created = Vec::new();
+
+        let saved = std::fs::read_to_string(root.join(self.page())).ok()
+            .filter(|_| self.examples.is_empty())
+            .map(|html| self.clone().with_page(&html));
+
+        for (path, contents) in saved.as_ref().unwrap_or(self).files() {
+            let path = root.join(path);
+
+            if path.exists() {
+                continue;
+            }
+
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| crate::report(format!("Failed to create {}: {e}", dir.display())))?;
+            }
+
+            std::fs::write(&path, contents)
+                .map_err(|e| crate::report(format!("Failed to write {}: {e}", path.display())))?;
+            created.push(path);
+        }
+
+        let main = root.join(&self.days).join("main.rs");
+        let source = std::fs::read_to_string(&main)
+            .map_err(|e| crate::report(format!("Failed to read {}: {e}", main.display())))?;
+
+        if let Some(registered) = self.register(&source) {
+            std::fs::write(&main, registered)
+                .map_err(|e| crate::report(format!("Failed to write {}: {e}", main.display())))?;
+            created.push(main);
+        }
+
+        Ok(created)
+    }
+
+    /// `main.rs` with the day added after the others, [None] if it's already there
+    pub fn register(&self, main: &str) -> Option<String> {
+        let module = self.module();
+        let declaration = format!("#[path = \"{}.rs\"]\nmod {module};\n", self.id);
+        let call = format!("    {module}::register(&mut registry);\n");
+
+        if main.contains(&format!("mod {module};")) {
+            return None;
+        }
+
+        let mut lines: Vec<String> = main.split_inclusive('\n').map(String::from).collect();
+
+        // after the last registration, or before the registry is returned
+        let call_at = lines.iter().rposition(|line| line.trim_end().ends_with("::register(&mut registry);"))
+            .map(|n| n + 1)
+            .or_else(|| lines.iter().position(|line| line.trim() == "registry"))?;
+        lines.insert(call_at, call);
+
+        // after the last module, or before the first `use`
+        let declare_at = lines.iter().rposition(|line| line.starts_with("mod "))
+            .map(|n| n + 1)
+            .or_else(|| lines.iter().position(|line| line.starts_with("use ")))?;
+        lines.insert(declare_at, declaration);
+
+        Some(lines.concat())
+    }
+
+    /// The day's source, a skeleton that the runner shows as pending
+    pub fn source(&self) -> String {
+        let (id, days) = (self.id, &self.days);
+        let (title, constructor) = match id {
+            PuzzleId::Aoc { year, day } => (format!("Day {day}"), format!("PuzzleId::aoc({year}, {day})")),
+            PuzzleId::Everybody { event, quest } => (format!("Quest {quest}"), format!("PuzzleId::everybody({event}, {quest})")),
+        };
+
+        let (imports, parse, solution, register, examples) = match (&self.args, id) {
+            (Some(ty), PuzzleId::Aoc { .. }) => (
+                "use std::str::FromStr;\n\nuse aoc_ornaments::{puzzle::PuzzleId, runner::Registry, ArgSolution, DefaultArgs, Part, SolutionReport, SolutionResult};".to_string(),
+                FROM_STR.to_string(),
+                format!("impl ArgSolution<{ty}> for Day {{\n    type Output = usize;\n}}\n\nimpl DefaultArgs<{ty}> for Day {{\n    /// from the puzzle text\n    fn args(_part: Part) -> {ty} {{\n        Default::default()\n    }}\n}}"),
+                format!("registry.arg_solution::<Day, {ty}>({constructor})"),
+                format!("aoc_ornaments::examples!(Day, {ty}, \"{days}/{id}.examples\");"),
+            ),
+            (None, PuzzleId::Aoc { .. }) => (
+                "use std::str::FromStr;\n\nuse aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution, SolutionReport, SolutionResult};".to_string(),
+                FROM_STR.to_string(),
+                SOLUTION.to_string(),
+                format!("registry.solution::<Day>({constructor})"),
+                format!("aoc_ornaments::examples!(Day, \"{days}/{id}.examples\");"),
+            ),
+            // quests always parse their notes, there's no argument to give them
+            (_, PuzzleId::Everybody { .. }) => (
+                "use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Notes, Part, Solution, SolutionResult};".to_string(),
+                NOTES.to_string(),
+                SOLUTION.to_string(),
+                format!("registry.quest::<Day>({constructor})"),
+                format!("aoc_ornaments::examples!(notes Day, \"{days}/{id}.examples\");"),
+            ),
+        };
+
+        format!("//! {title}: Untitled\n\n{imports}\n\n#[derive(Debug)]\nstruct Day;\n\n{parse}\n\n{solution}\n\npub fn register(registry: &mut Registry) {{\n    {register}\n        .title({constructor}, \"Untitled\");\n}}\n\n#[cfg(test)]\nmod tests {{\n    use super::*;\n\n    {examples}\n}}\n")
+    }
+}
+
+const FROM_STR: &str = "impl FromStr for Day {
+    type Err = SolutionReport;
+
+    fn from_str(_input: &str) -> SolutionResult<Self> {
+        Ok(Self)
+    }
+}";
+
+const NOTES: &str = "impl Notes<'_> for Day {
+    fn parse_notes(_notes: &str, _part: Part) -> SolutionResult<Self> {
+        Ok(Self)
+    }
+}";
+
+const SOLUTION: &str = "impl Solution for Day {
+    type Output = usize;
+}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Scaffold::from_args(args("2015 6")).unwrap(), Scaffold::new(PuzzleId::aoc(2015, 6)));
+        assert_eq!(Scaffold::from_args(args("2015 7 --args u32")).unwrap(), Scaffold::new(PuzzleId::aoc(2015, 7)).with_args("u32"));
+        assert!(Scaffold::from_args(args("e2024 1 --args u32")).is_err());
+        assert!(Scaffold::from_args(args("2015")).is_err());
+        assert!(Scaffold::from_args(args("2015 6 1")).is_err());
+    }
+
+    #[test]
+    fn test_files() {
+        let names = |scaffold: Scaffold| scaffold.files().into_iter().map(|(path, _)| path.display().to_string()).collect::<Vec<_>>();
+
+        assert_eq!(names(Scaffold::new(PuzzleId::aoc(2015, 6))), vec![
+            "src/bin/aoc/2015-12-06.rs",
+            "src/bin/aoc/2015-12-06.examples",
+            "src/inputs/2015-12-06.txt",
+        ]);
+        assert_eq!(names(Scaffold::new(PuzzleId::everybody(2024, 1))).len(), 5);
+
+        // a quest never gets an argument, even when given one
+        let quest = Scaffold::new(PuzzleId::everybody(2024, 1)).with_args("u32").source();
+        assert!(quest.contains("registry.quest::<Day>") && !quest.contains("ArgSolution"));
+    }
+
+    #[test]
+    fn test_register() {
+        let main = "use aoc_ornaments::runner::Registry;\n\n#[path = \"2015-12-02.rs\"]\nmod day_2015_12_02;\n\nfn registry() -> Registry {\n    let mut registry = Registry::new();\n\n    day_2015_12_02::register(&mut registry);\n\n    registry\n}\n";
+        let scaffold = Scaffold::new(PuzzleId::aoc(2015, 3));
+
+        let registered = scaffold.register(main).unwrap();
+        assert!(registered.contains("mod day_2015_12_02;\n#[path = \"2015-12-03.rs\"]\nmod day_2015_12_03;\n"));
+        assert!(registered.contains("    day_2015_12_02::register(&mut registry);\n    day_2015_12_03::register(&mut registry);\n"));
+        assert_eq!(scaffold.register(&registered), None);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join("aoc-ornaments-scaffold");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join(DAYS)).unwrap();
+        std::fs::write(root.join(DAYS).join("main.rs"), "use aoc_ornaments::runner::Registry;\n\nfn registry() -> Registry {\n    let mut registry = Registry::new();\n\n    registry\n}\n").unwrap();
+
+        std::fs::create_dir_all(root.join(INPUTS)).unwrap();
+        std::fs::write(root.join(INPUTS).join("2015-12-06.html"), "<pre><code>on\n</code></pre> <code><em>1</em></code>").unwrap();
+
+        let scaffold = Scaffold::new(PuzzleId::aoc(2015, 6));
+        assert_eq!(scaffold.create(&root).unwrap().len(), 4);
+        assert_eq!(std::fs::read_to_string(root.join(DAYS).join("2015-12-06.examples")).unwrap(), "=== part 1 -> 1\non\n");
+        // nothing is overwritten the second time
+        std::fs::write(root.join(DAYS).join("2015-12-06.rs"), "mine").unwrap();
+        assert!(scaffold.create(&root).unwrap().is_empty());
+        assert_eq!(std::fs::read_to_string(root.join(DAYS).join("2015-12-06.rs")).unwrap(), "mine");
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -1,7 +1,8 @@
 use std::{collections::{HashMap, HashSet}, str::FromStr};
-use miette::Diagnostic;
 use thiserror::Error;
 
+use crate::SolutionError;
+
 pub type Position = glam::IVec2;
 pub type Velocity = glam::IVec2;
 
@@ -20,118 +21,501 @@ pub const DIAGONALS: [Position; 4] = [Position::NEG_ONE, Position::new(-1, 1), P
 /// Up, SE, Right, NE, Down, NW, Left, SW
 pub const ALL_DIRECTIONS: [Position; 8] = [Position::NEG_Y, Position::ONE, Position::X, Position::new(1, -1), Position::Y, Position::NEG_ONE, Position::NEG_X, Position::new(-1, 1)];
 
-#[derive(Debug, derive_more::Deref, derive_more::DerefMut)]
-pub struct Grid<T>(pub Vec<Vec<T>>);
+/// What happens past the edges of a [Grid]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// nothing is out there
+    #[default]
+    Bounded,
+    /// leaving one edge comes back in at the opposite one, a torus
+    Wrapping,
+}
 
-impl FromStr for Grid<char> {
-    type Err = miette::Error;
+/// A rectangle of cells, stored row by row in a single buffer
+///
+/// Index it by [Position], `grid[pos]`, or by row then column as before, `grid[y][x]`.
+/// A [Topology::Wrapping] grid wraps every position it's given, so the same code walks either kind.
+/// That makes the topology part of what a grid is: grids are only equal, and hash alike, with the same cells, size and topology.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    topology: Topology,
+}
 
-    fn from_str(input: &str) -> miette::Result<Self> {
-        Ok(Self(input.lines()
-            .map(|line| line.chars().collect()).collect()))
+/// empty, whether or not `T` has a default
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self { cells: Vec::new(), width: 0, height: 0, topology: Topology::Bounded }
     }
 }
 
-impl FromStr for Grid<bool> {
-    type Err = miette::Error;
+/// A cell that parses from a single character, for `input.parse::<Grid<T>>()`, and displays as one
+///
+/// Digits are ready-made for the unsigned integer types, and [tiles!](crate::tiles) writes an enum of tiles along with its impl.
+/// `to_char` should give back a character that `from_char` maps to the same cell, so a grid survives `to_string().parse()`,
+/// digits only manage that up to 9.
+pub trait Cell: Sized {
+    /// [None] for a character that isn't a valid cell
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
 
-    fn from_str(input: &str) -> miette::Result<Self> {
-        Ok(Self(input.lines()
-            .map(|line| line.chars().map(|c| c == '#').collect()).collect()))
+impl Cell for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
     }
 }
 
-impl<T: std::fmt::Debug + Copy + PartialEq> Grid<T> {
-    pub fn initialize(width: usize, height: usize, value: T) -> Self {
-        let mut grid = Vec::with_capacity(height);
-        for _ in 0..height {
-            let row = vec![value; width];
-            grid.push(row);
+/// `#` is true, anything else false, and false displays as `.`
+impl Cell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c == '#')
+    }
+
+    fn to_char(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+/// Lossy, anything past 9 displays as `?`, which doesn't parse back
+macro_rules! digit_cells {
+    ($($ty:ty),+) => {
+        $(impl Cell for $ty {
+            fn from_char(c: char) -> Option<Self> {
+                c.to_digit(10).map(|digit| digit as $ty)
+            }
+
+            fn to_char(&self) -> char {
+                u32::try_from(*self).ok().and_then(|digit| char::from_digit(digit, 10)).unwrap_or('?')
+            }
+        })+
+    };
+}
+
+digit_cells!(u8, u16, u32, u64, usize);
+
+/// An enum of tiles, each with its own character, that parses and displays as a [Cell]
+///
+/// ```ignore
+/// aoc_ornaments::tiles! {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     enum Tile {
+///         Wall = '#',
+///         Open = '.',
+///     }
+/// }
+///
+/// let maze: Grid<Tile> = input.parse()?;
+/// ```
+#[macro_export]
+macro_rules! tiles {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident { $($(#[$variant_meta:meta])* $variant:ident = $c:literal),+ $(,)? }) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
         }
 
-        Self(grid)
+        impl $crate::spatial::Cell for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($c => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $(Self::$variant => $c,)+
+                }
+            }
+        }
+    };
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = SolutionError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(input, T::from_char)
     }
+}
 
-    pub fn get_width(&self) -> usize {
-        self[0].len()
+/// One line per row, the inverse of [FromStr]
+impl<T: Cell> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(T::to_char).fmt(f)
     }
+}
 
-    pub fn get_height(&self) -> usize {
-        self.len()
+/// A grid drawn with any glyphs, with positions picked out on top, see [Grid::render]
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: Box<dyn Fn(&T) -> char + 'a>,
+    overlay: HashMap<Position, char>,
+}
+
+impl<T> Render<'_, T> {
+    /// Draw `glyph` over each position, wrapped onto a wrapping grid, on top of anything already highlighted
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Position>, glyph: char) -> Self {
+        let grid = self.grid;
+        self.overlay.extend(positions.into_iter().filter_map(|pos| grid.wrap(pos)).map(|pos| (pos, glyph)));
+        self
     }
 
-    pub fn get_at_unbounded(&self, pos: Position) -> T {
-        self[pos.y as usize][pos.x as usize]
+    /// Draw an arrow at each step towards the next, the last step is left as it is
+    ///
+    /// On a wrapping grid, a step across an edge points off that edge, the way it went.
+    pub fn path(mut self, path: impl IntoIterator<Item = Position>) -> Self {
+        const ARROWS: [char; 4] = ['^', '>', 'v', '<'];
+        let mut path = path.into_iter().peekable();
+
+        while let (Some(pos), Some(&next)) = (path.next(), path.peek()) {
+            let Some(at) = self.grid.wrap(pos) else { continue };
+            let arrows = || DIRECTIONS.into_iter().zip(ARROWS);
+
+            // a plain step first, so a 2 wide grid's arrows don't depend on which way round is shorter
+            let arrow = arrows().find(|&(delta, _)| next - pos == delta)
+                .or_else(|| arrows().find(|&(delta, _)| self.grid.step(at, delta).is_some_and(|to| Some(to) == self.grid.wrap(next))))
+                .map_or('*', |(_, arrow)| arrow);
+
+            self.overlay.insert(at, arrow);
+        }
+
+        self
     }
+}
 
-    /// Bounded by the grid's dimensions
-    pub fn get_at(&self, pos: Position) -> Option<T> {
-        // if pos.x < 0 || pos.y < 0 || pos.x >= self.get_width() as i32 || pos.y >= self.get_height() as i32 {
-        if !self.in_bounds(pos) {
-            return None;
+impl<T> std::fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for (x, cell) in row.iter().enumerate() {
+                let glyph = self.overlay.get(&Position::new(x as i32, y as i32)).copied()
+                    .unwrap_or_else(|| (self.glyph)(cell));
+
+                write!(f, "{glyph}")?;
+            }
         }
 
-        Some(self[pos.y as usize][pos.x as usize])
-        // Some(self.get_at_unbounded(pos))
+        Ok(())
     }
-    /// ORTHOGONAL neighbors. use [Self::get_all_neighbors] for all 8
-    pub fn get_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
-        let mut neighbors = Vec::with_capacity(4);
+}
+
+impl<T> Grid<T> {
+    /// `cells` row by row, which must fill every row
+    ///
+    /// # Panics
+    ///
+    /// if `cells` doesn't divide into rows of `width`
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        if width == 0 {
+            assert!(cells.is_empty(), "{} cells can't have a width of 0", cells.len());
+            return Self::default();
+        }
+
+        assert_eq!(cells.len() % width, 0, "{} cells don't divide into rows of {width}", cells.len());
 
-        for delta in DIRECTIONS.iter() {
-            let new_pos = pos + *delta;
+        Self { height: cells.len() / width, cells, width, topology: Topology::Bounded }
+    }
 
-            // Boundary check matching the working version
-            if new_pos.x >= 0 && new_pos.x < self.get_width() as i32 && 
-               new_pos.y >= 0 && new_pos.y < self.get_height() as i32 {
-                neighbors.push((new_pos, self.get_at_unbounded(new_pos)));
+    /// Rows of equal length, as the old `Vec<Vec<T>>` grids were built
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Result<Self, SolutionError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+
+        for (n, row) in rows.into_iter().enumerate() {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(SolutionError::invalid_argument("rows", format!("row {n} has {len} cells, expected {width}")));
+                },
+                _ => (),
             }
         }
 
-        neighbors
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
     }
 
-    pub fn get_all_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
-        let mut neighbors = Vec::with_capacity(8);
+    /// One cell per character, every line the same length
+    ///
+    /// A character `cell` rejects is reported by its row and column, counted from zero like a [Position].
+    pub fn parse_with(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, SolutionError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut start = 0;
+
+        for (y, line) in input.split_inclusive('\n').enumerate() {
+            let offset = start;
+            start += line.len();
+            let line = line.strip_suffix('\n').unwrap_or(line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            let before = cells.len();
+
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let Some(value) = cell(c) else {
+                    let at = offset + i;
+                    return Err(SolutionError::parse(input, at..at + c.len_utf8(), format!("unexpected `{c}` at row {y}, column {x}")));
+                };
+
+                cells.push(value);
+            }
 
-        for delta in ALL_DIRECTIONS.iter() {
-            let new_pos = pos + *delta;
+            let len = cells.len() - before;
 
-            // Boundary check matching the working version
-            if new_pos.x >= 0 && new_pos.x < self.get_width() as i32 && 
-               new_pos.y >= 0 && new_pos.y < self.get_height() as i32 {
-                neighbors.push((new_pos, self.get_at_unbounded(new_pos)));
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => return Err(SolutionError::line(input, y, format!("expected {width} cells, found {len}"))),
+                _ => (),
             }
         }
 
-        neighbors
+        Ok(Self::from_vec(width.unwrap_or_default(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every cell, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_exact_mut(self.width.max(1))
+    }
+
+    /// Columns aren't contiguous, so this steps through the rows, [None] past the last one
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Shorthand for [Topology::Wrapping]
+    pub fn wrapping(self) -> Self {
+        self.with_topology(Topology::Wrapping)
+    }
+
+    /// Where `pos` really is: itself if it's inside, wrapped around for a wrapping grid, otherwise [None]
+    pub fn wrap(&self, pos: Position) -> Option<Position> {
+        match self.topology {
+            _ if self.contains(pos) => Some(pos),
+            Topology::Wrapping if !self.is_empty() => Some(Position::new(pos.x.rem_euclid(self.width as i32), pos.y.rem_euclid(self.height as i32))),
+            _ => None,
+        }
+    }
+
+    /// Move from `pos` by `delta`, wrapping if the grid does
+    pub fn step(&self, pos: Position, delta: Position) -> Option<Position> {
+        self.wrap(pos + delta)
+    }
+
+    /// Where `pos` is in [cells](Self::cells), after wrapping
+    pub fn index_of(&self, pos: Position) -> Option<usize> {
+        self.wrap(pos).map(|pos| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// Where the `index`th of [cells](Self::cells) is, [None] past the last one
+    pub fn position_of(&self, index: usize) -> Option<Position> {
+        (index < self.cells.len()).then(|| Position::new((index % self.width) as i32, (index / self.width) as i32))
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
     }
 
+    /// Every position, top-left to bottom-right
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| Position::new((i % width) as i32, (i / width) as i32))
+    }
+
+    /// Rows, as iterating the nested `Vec<Vec<T>>` this used to be did, see [Grid::indexed] for cells
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.rows()
+    }
+
+    /// Every cell with its position, top-left to bottom-right
+    pub fn indexed(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Whether `pos` leads to a cell, which is anywhere on a non-empty wrapping grid
     pub fn in_bounds(&self, pos: Position) -> bool {
-        pos.x >= 0 && pos.y >= 0 && pos.x < self.get_width() as i32 && pos.y < self.get_height() as i32
+        self.wrap(pos).is_some()
     }
 
-    pub fn set_at(&mut self, pos: Position, value: T) -> Option<()> {
-        if !self.in_bounds(pos) {
-            return None;
+    /// Whether `pos` is inside the rectangle, whatever the topology
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.width as i32 && pos.y < self.height as i32
+    }
+
+    /// Draw each cell as `glyph` gives it, then add overlays: `grid.render(Cell::to_char).path(path)`
+    pub fn render<'a>(&'a self, glyph: impl Fn(&T) -> char + 'a) -> Render<'a, T> {
+        Render { grid: self, glyph: Box::new(glyph), overlay: HashMap::new() }
+    }
+
+    /// because Position is a type and not a NewType, we can't impl FromStr for it
+    pub fn position_from_str(s: &str) -> Result<Position, SolutionError> {
+        let Some((x, y)) = s.split_once(',') else {
+            return Err(SolutionError::parse(s, 0..s.len(), "Expected a position, x,y"));
+        };
+
+        let x = x.parse().map_err(|_| SolutionError::parse(s, 0..x.len(), "Invalid x"))?;
+        let y = y.parse().map_err(|_| SolutionError::parse(s, s.len() - y.len()..s.len(), "Invalid y"))?;
+
+        Ok(Position::new(x, y))
+    }
+}
+
+/// the whole row, for `grid[y][x]`
+impl<T> std::ops::Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        self.row(y)
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        self.row_mut(y)
+    }
+}
+
+impl<T> std::ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{pos} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> std::ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos).unwrap_or_else(|| panic!("{pos} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn initialize(width: usize, height: usize, value: T) -> Self {
+        Self::from_vec(width, vec![value; width * height])
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn get_at_unbounded(&self, pos: Position) -> T {
+        self[pos]
+    }
+
+    /// Bounded by the grid's dimensions
+    pub fn get_at(&self, pos: Position) -> Option<T> {
+        self.get(pos).copied()
+    }
+
+    /// ORTHOGONAL neighbors, at their wrapped positions. use [Self::get_all_neighbors] for all 8
+    pub fn get_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
+        self.neighbors(pos, &DIRECTIONS)
+    }
+
+    pub fn get_all_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
+        self.neighbors(pos, &ALL_DIRECTIONS)
+    }
+
+    /// Each cell once, and never `pos` itself, as directions meet again around a wrapping grid 2 or fewer wide or tall
+    fn neighbors(&self, pos: Position, deltas: &[Position]) -> Vec<(Position, T)> {
+        let here = self.wrap(pos);
+        let mut neighbors: Vec<(Position, T)> = Vec::with_capacity(deltas.len());
+
+        for next in deltas.iter().filter_map(|&delta| self.step(pos, delta)) {
+            if Some(next) != here && neighbors.iter().all(|&(seen, _)| seen != next) {
+                neighbors.push((next, self[next]));
+            }
         }
-        self[pos.y as usize][pos.x as usize] = value;
+
+        neighbors
+    }
+
+    pub fn set_at(&mut self, pos: Position, value: T) -> Option<()> {
+        *self.get_mut(pos)? = value;
         Some(())
     }
 
     // Unbounded version if you need it
     pub fn set_at_unbounded(&mut self, pos: Position, value: T) {
-        self[pos.y as usize][pos.x as usize] = value;
+        self[pos] = value;
     }
 
     /// Walks the grid from top-left to bottom-right
     pub fn walk<F: FnMut(Position) -> O, O>(&self, mut see: F) {
-        for row in 0..self.get_height() {
-            for col in 0..self.get_width() {
-                let pos = Position::new(col as i32, row as i32);
-
-                see(pos);
-            }
+        for pos in self.positions() {
+            see(pos);
         }
     }
 
@@ -155,11 +539,178 @@ impl<T: std::fmt::Debug + Copy + PartialEq> Grid<T> {
     //         }
     //     }
     // }
+}
 
-    /// because Position is a type and not a NewType, we can't impl FromStr for it
-    pub fn position_from_str(s: &str) -> miette::Result<Position> {
-        let parts: Vec<i32> = s.split(',').map(|s| s.parse().unwrap()).collect();
-        Ok(Position::new(parts[0], parts[1]))
+/// An unbounded plane of cells, where only the cells that were set are stored and the rest are `default`
+///
+/// It keeps the bounding box of the stored cells, which is what it converts to a dense [Grid] and displays as.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    default: T,
+    /// top-left and bottom-right, inclusive
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self { cells: HashMap::new(), default, bounds: None }
+    }
+
+    /// Every cell of `grid` is stored, so converting back gives the same grid
+    pub fn from_grid(grid: Grid<T>, default: T) -> Self {
+        let mut sparse = Self::new(default);
+        let positions: Vec<_> = grid.positions().collect();
+
+        for (pos, cell) in positions.into_iter().zip(grid.into_cells()) {
+            sparse.insert(pos, cell);
+        }
+
+        sparse
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Cells that were set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top-left and bottom-right of the stored cells, inclusive
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// The default for anywhere that wasn't set
+    pub fn get(&self, pos: Position) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Set a cell, returning what was stored there
+    pub fn insert(&mut self, pos: Position, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(pos), max.max(pos)),
+            None => (pos, pos),
+        });
+
+        self.cells.insert(pos, value)
+    }
+
+    /// Back to the default, shrinking the bounds if it was on an edge
+    pub fn remove(&mut self, pos: Position) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+
+        let on_edge = self.bounds.is_some_and(|(min, max)| pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y);
+
+        if on_edge {
+            self.bounds = self.cells.keys().fold(None, |bounds, &pos| Some(match bounds {
+                Some((min, max)) => (pos.min(min), pos.max(max)),
+                None => (pos, pos),
+            }));
+        }
+
+        Some(removed)
+    }
+
+    /// Stored cells, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.iter().map(|(&pos, cell)| (pos, cell))
+    }
+
+    /// Every position within the bounds, top-left to bottom-right
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let (min, max) = self.bounds.unwrap_or((Position::ONE, Position::ZERO));
+
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Position::new(x, y)))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// The cell at `pos`, set to the default first if it wasn't already
+    pub fn entry(&mut self, pos: Position) -> &mut T {
+        if !self.contains(pos) {
+            self.insert(pos, self.default.clone());
+        }
+
+        self.cells.get_mut(&pos).expect("just inserted")
+    }
+
+    /// The bounds as a dense grid, the top-left of the bounds becomes [Position::ZERO]
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some((min, _)) = self.bounds else {
+            return Grid::default();
+        };
+
+        let mut grid = Grid::initialize(self.width(), self.height(), self.default.clone());
+
+        for (&pos, cell) in &self.cells {
+            grid[pos - min] = cell.clone();
+        }
+
+        grid
+    }
+}
+
+impl<T: Copy> SparseGrid<T> {
+    pub fn get_at(&self, pos: Position) -> T {
+        *self.get(pos)
+    }
+
+    pub fn set_at(&mut self, pos: Position, value: T) {
+        self.insert(pos, value);
+    }
+
+    /// ORTHOGONAL neighbors, there are always 4. use [Self::get_all_neighbors] for all 8
+    pub fn get_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
+        DIRECTIONS.iter().map(|delta| (pos + *delta, self.get_at(pos + *delta))).collect()
+    }
+
+    pub fn get_all_neighbors(&self, pos: Position) -> Vec<(Position, T)> {
+        ALL_DIRECTIONS.iter().map(|delta| (pos + *delta, self.get_at(pos + *delta))).collect()
+    }
+
+    /// Walks the bounds from top-left to bottom-right
+    pub fn walk<F: FnMut(Position) -> O, O>(&self, mut see: F) {
+        for pos in self.positions() {
+            see(pos);
+        }
+    }
+}
+
+impl<T: Default> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        Self::from_grid(grid, T::default())
+    }
+}
+
+/// The bounds, as the dense grid would display
+impl<T: Cell + Clone> std::fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_grid().fmt(f)
     }
 }
 
@@ -187,18 +738,19 @@ pub enum Direction {
     Right
 }
 
-#[derive(Error, Diagnostic, Debug)]
+#[derive(Error, Debug)]
+#[cfg_attr(feature = "miette", derive(miette::Diagnostic))]
 pub enum DirectionError {
     #[error("Invalid direction character: {0}")]
-    #[diagnostic(code(direction::invalid_char))]
+    #[cfg_attr(feature = "miette", diagnostic(code(direction::invalid_char)))]
     InvalidChar(char),
 
     #[error("Invalid direction string: {0}")]
-    #[diagnostic(code(direction::invalid_str))]
+    #[cfg_attr(feature = "miette", diagnostic(code(direction::invalid_str)))]
     InvalidStr(String),
 
     #[error("Invalid direction mapping: Expected 4 unique directions")]
-    #[diagnostic(code(direction::invalid_mapping))]
+    #[cfg_attr(feature = "miette", diagnostic(code(direction::invalid_mapping)))]
     InvalidMapping,
 
     // #[error("Invalid symbol: {0}")]
@@ -209,7 +761,7 @@ pub enum DirectionError {
 impl FromStr for Direction {
     type Err = DirectionError;
 
-    fn from_str(s: &str) -> miette::Result<Self, Self::Err> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "^" | "up" | "a" | "north" | "n" => Ok(Direction::Up),
             "v" | "down" | "x" | "south" | "s" => Ok(Direction::Down),
@@ -244,7 +796,7 @@ impl Direction {
     }
 
     /// for parsing from a CHAR, otherwise use [FromStr] because we get [String.parse] for free
-    pub fn parse(c: char) -> miette::Result<Self, DirectionError> {
+    pub fn parse(c: char) -> Result<Self, DirectionError> {
         match c.to_ascii_lowercase() {
             '^' | 'a' | 'n' => Ok(Direction::Up),
             'v' | 'x' | 's' => Ok(Direction::Down),
@@ -292,6 +844,170 @@ impl Direction {
 mod tests {
     use super::*;
 
-    
+    #[test]
+    fn test_grid() {
+        let mut grid: Grid<char> = "abc\ndef".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).unwrap().collect::<String>(), "be");
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.rows().len(), 2);
+        assert_eq!(grid[Position::new(2, 1)], 'f');
+        assert_eq!(grid[1][2], 'f');
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.position_of(4), Some(Position::new(1, 1)));
+        assert_eq!(grid.position_of(6), None);
+
+        grid[Position::new(0, 1)] = 'x';
+        grid[0][0] = 'y';
+        assert_eq!(grid.cells().iter().collect::<String>(), "ybcxef");
+        assert_eq!(grid.get_neighbors(Position::ZERO), vec![(Position::X, 'b'), (Position::Y, 'x')]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Grid<bool> = "".parse().unwrap();
+
+        assert!(grid.is_empty());
+        assert_eq!((grid.get_width(), grid.get_height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.get_at(Position::ZERO), None);
+        assert!(grid.column(0).is_none());
+        assert_eq!(grid.position_of(0), None);
+        assert_eq!(Grid::<u8>::initialize(0, 5, 0), Grid::default());
+    }
+
+    crate::tiles! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Tile {
+            Wall = '#',
+            /// nothing in the way
+            Open = '.',
+        }
+    }
+
+    #[test]
+    fn test_cells() {
+        let heights: Grid<u8> = "012\n789".parse().unwrap();
+        assert_eq!(heights.row(1), [7, 8, 9]);
+        assert_eq!(heights.iter().collect::<Vec<_>>(), [[0, 1, 2], [7, 8, 9]]);
+        assert_eq!(heights.indexed().nth(4), Some((Position::new(1, 1), &8)));
+        assert_ne!(heights.clone().wrapping(), heights);
+
+        let maze: Grid<Tile> = "#.\r\n.#\r\n".parse().unwrap();
+        assert_eq!(maze[Position::new(1, 1)], Tile::Wall);
+        assert_eq!(maze.cells(), [Tile::Wall, Tile::Open, Tile::Open, Tile::Wall]);
+
+        let lights: Grid<bool> = ".#".parse().unwrap();
+        assert_eq!(lights.cells(), [false, true]);
+    }
+
+    #[test]
+    fn test_digits() {
+        let mut heights: Grid<u32> = "09".parse().unwrap();
+        assert_eq!(heights.to_string().parse::<Grid<u32>>(), Ok(heights.clone()));
+
+        heights[0][1] = 10;
+        assert_eq!(heights.to_string(), "0?");
+        assert!(heights.to_string().parse::<Grid<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for input in ["#..#\n.##.", ""] {
+            let maze: Grid<Tile> = input.parse().unwrap();
+            assert_eq!(maze.to_string().parse::<Grid<Tile>>().unwrap(), maze);
+            assert_eq!(maze.to_string(), input);
+        }
+
+        let heights: Grid<u8> = "012\n789".parse().unwrap();
+        assert_eq!(heights.to_string(), "012\n789");
+        assert_eq!(heights.render(|&h| if h > 5 { '^' } else { '_' }).to_string(), "___\n^^^");
+    }
 
+    #[test]
+    fn test_overlays() {
+        let grid = Grid::initialize(3, 3, false);
+        let path = [Position::ZERO, Position::X, Position::ONE, Position::new(1, 2)];
+
+        assert_eq!(grid.render(Cell::to_char).path(path).to_string(), ">v.\n.v.\n...");
+        assert_eq!(grid.render(|_| ' ').highlight([Position::ZERO, Position::new(2, 2)], 'O').to_string(), "O  \n   \n  O");
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let bounded: Grid<u8> = "123\n456".parse().unwrap();
+        let torus = bounded.clone().wrapping();
+
+        assert_eq!(bounded.get_at(Position::new(-1, 0)), None);
+        assert_eq!(torus.get_at(Position::new(-1, 0)), Some(3));
+        assert_eq!(torus[Position::new(4, -3)], 5);
+        assert_eq!(torus.step(Position::new(2, 1), Position::ONE), Some(Position::ZERO));
+        assert_eq!(bounded.step(Position::new(2, 1), Position::ONE), None);
+
+        assert_eq!(bounded.get_neighbors(Position::ZERO).len(), 2);
+        // up and down meet on a grid 2 tall
+        assert_eq!(torus.get_neighbors(Position::ZERO), vec![(Position::Y, 4), (Position::X, 2), (Position::new(2, 0), 3)]);
+        assert_eq!(torus.get_all_neighbors(Position::ZERO).len(), 5);
+        assert_eq!("1".parse::<Grid<u8>>().unwrap().wrapping().get_all_neighbors(Position::ZERO), vec![]);
+        assert_eq!(torus.render(|_| '.').path([Position::new(2, 1), Position::new(0, 1), Position::new(0, 0)]).to_string(), "...\n^.>");
+        assert_eq!(torus.render(|_| '.').highlight([Position::new(-1, -1)], '#').to_string(), "...\n..#");
+        assert!(torus.in_bounds(Position::new(100, 100)) && !torus.contains(Position::new(100, 100)));
+        assert!(!Grid::<u8>::default().wrapping().in_bounds(Position::ZERO));
+    }
+
+    #[test]
+    fn test_position_from_str() {
+        assert_eq!(Grid::<bool>::position_from_str("3,-4"), Ok(Position::new(3, -4)));
+        assert_eq!(Grid::<bool>::position_from_str("3"), Err(SolutionError::parse("3", 0..1, "Expected a position, x,y")));
+        assert_eq!(Grid::<bool>::position_from_str("a,4"), Err(SolutionError::parse("a,4", 0..1, "Invalid x")));
+        assert_eq!(Grid::<bool>::position_from_str("3,4,5"), Err(SolutionError::parse("3,4,5", 2..5, "Invalid y")));
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut sparse = SparseGrid::new('.');
+        assert_eq!((sparse.bounds(), sparse.to_string()), (None, String::new()));
+
+        sparse.insert(Position::new(-1, -1), '#');
+        sparse.insert(Position::new(1, 0), '#');
+        *sparse.entry(Position::ZERO) = 'o';
+
+        assert_eq!(sparse.bounds(), Some((Position::NEG_ONE, Position::X)));
+        assert_eq!((sparse.width(), sparse.height()), (3, 2));
+        assert_eq!(sparse.get_at(Position::new(50, -50)), '.');
+        assert_eq!(sparse.get_neighbors(Position::ZERO), vec![(Position::NEG_Y, '.'), (Position::X, '#'), (Position::Y, '.'), (Position::NEG_X, '.')]);
+        assert_eq!(sparse.positions().count(), 6);
+        assert_eq!(sparse.to_string(), "#..\n.o#");
+
+        sparse.remove(Position::new(-1, -1));
+        assert_eq!(sparse.bounds(), Some((Position::ZERO, Position::X)));
+        assert_eq!(sparse.to_grid(), "o#".parse().unwrap());
+    }
+
+    #[test]
+    fn test_sparse_round_trip() {
+        let grid: Grid<u8> = "000\n010".parse().unwrap();
+        let sparse = SparseGrid::from(grid.clone());
+
+        assert_eq!(sparse.len(), 6);
+        assert_eq!(sparse.get_at(Position::ONE), 1);
+        assert_eq!(sparse.to_grid(), grid);
+    }
+
+    #[test]
+    fn test_bad_cell() {
+        let input = "12\n3x";
+
+        assert_eq!("12\n3x".parse::<Grid<u8>>(), Err(SolutionError::parse(input, 4..5, "unexpected `x` at row 1, column 1")));
+        assert!("#?".parse::<Grid<Tile>>().is_err());
+    }
+
+    #[test]
+    fn test_ragged() {
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!(Grid::from_rows([vec![1, 2], vec![3]]).is_err());
+        assert_eq!(Grid::from_rows([vec![1, 2], vec![3, 4]]).unwrap(), Grid::from_vec(2, vec![1, 2, 3, 4]));
+    }
 }
\ No newline at end of file
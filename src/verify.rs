@@ -0,0 +1,123 @@
+//! Accepted answers, to catch a refactor of a shared module silently breaking an old solution
+//!
+//! Stored as JSON, keyed by puzzle then part: `{"2015-12-04": {"1": "282749", "2": "9962624"}}`
+
+use std::{collections::BTreeMap, path::Path};
+
+use serde_json::{Map, Value};
+
+use crate::{puzzle::PuzzleId, Part, SolutionResult};
+
+/// How an answer compares to the accepted one
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    /// nothing has been accepted yet
+    New,
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers(pub BTreeMap<(PuzzleId, Part), String>);
+
+impl Answers {
+    /// A missing file has no answers yet
+    pub fn load(path: impl AsRef<Path>) -> SolutionResult<Self> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| crate::report(format!("Failed to read {}: {e}", path.display())))?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| crate::report(format!("Invalid answers file {}: {e}", path.display())))?;
+
+        let mut answers = Self::default();
+
+        for (id, parts) in json.as_object().into_iter().flatten() {
+            for (part, answer) in parts.as_object().into_iter().flatten() {
+                let (Ok(key), Ok(part), Some(answer)) = (id.parse(), part.parse(), answer.as_str()) else {
+                    return Err(crate::report(format!("Invalid answer for {id} part {part} in {}", path.display())));
+                };
+
+                answers.0.insert((key, part), answer.to_string());
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> SolutionResult<()> {
+        let path = path.as_ref();
+        let mut json = Map::new();
+
+        for ((id, part), answer) in &self.0 {
+            let parts = json.entry(id.to_string()).or_insert_with(|| Value::Object(Map::new()));
+            parts[part.number().to_string()] = Value::String(answer.clone());
+        }
+
+        let text = serde_json::to_string_pretty(&Value::Object(json)).expect("json values always serialize");
+        std::fs::write(path, text + "\n")
+            .map_err(|e| crate::report(format!("Failed to write {}: {e}", path.display())))
+    }
+
+    pub fn get(&self, id: &PuzzleId, part: Part) -> Option<&String> {
+        self.0.get(&(*id, part))
+    }
+
+    /// Accept an answer, replacing any previous one
+    pub fn record(&mut self, id: &PuzzleId, part: Part, answer: &str) {
+        self.0.insert((*id, part), answer.to_string());
+    }
+
+    pub fn check(&self, id: &PuzzleId, part: Part, answer: &str) -> Verdict {
+        match self.get(id, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone() },
+            None => Verdict::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let id = PuzzleId::aoc(2015, 4);
+        let mut answers = Answers::default();
+        answers.record(&id, Part::One, "282749");
+
+        assert_eq!(answers.check(&id, Part::One, "282749"), Verdict::Pass);
+        assert_eq!(answers.check(&id, Part::One, "42"), Verdict::Fail { expected: "282749".into() });
+        assert_eq!(answers.check(&id, Part::Two, "42"), Verdict::New);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir().join("aoc-ornaments-answers.json");
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+
+        let mut answers = Answers::default();
+        answers.record(&PuzzleId::aoc(2015, 4), Part::One, "282749");
+        answers.record(&PuzzleId::everybody(2024, 1), Part::Three, "hello");
+        answers.save(&path).unwrap();
+
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,38 @@
+//! What `new` writes, compiled here so a template that doesn't build is caught before anyone scaffolds a day with it
+//!
+//! After changing a template, write the files again with `Scaffold::with_days("tests/scaffold")`.
+
+use aoc_ornaments::{puzzle::PuzzleId, runner::{Registry, Selection}, scaffold::Scaffold};
+
+#[path = "scaffold/2015-12-06.rs"]
+mod day_2015_12_06;
+#[path = "scaffold/2015-12-07.rs"]
+mod day_2015_12_07;
+#[path = "scaffold/everybody_codes_e2024_q01.rs"]
+mod everybody_codes_e2024_q01;
+
+const DAYS: &str = "tests/scaffold";
+
+#[test]
+fn test_templates() {
+    let templates = [
+        (Scaffold::new(PuzzleId::aoc(2015, 6)), include_str!("scaffold/2015-12-06.rs")),
+        (Scaffold::new(PuzzleId::aoc(2015, 7)).with_args("u32"), include_str!("scaffold/2015-12-07.rs")),
+        (Scaffold::new(PuzzleId::everybody(2024, 1)), include_str!("scaffold/everybody_codes_e2024_q01.rs")),
+    ];
+
+    for (scaffold, source) in templates {
+        let scaffold = scaffold.with_days(DAYS);
+        assert_eq!(scaffold.source(), source, "{} is out of date", scaffold.id);
+    }
+}
+
+#[test]
+fn test_register() {
+    let mut registry = Registry::new();
+    day_2015_12_06::register(&mut registry);
+    day_2015_12_07::register(&mut registry);
+    everybody_codes_e2024_q01::register(&mut registry);
+
+    assert_eq!(registry.select(&Selection::default()).count(), 7);
+}
@@ -0,0 +1,32 @@
+//! Day 6: Untitled
+
+use std::str::FromStr;
+
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Solution, SolutionReport, SolutionResult};
+
+#[derive(Debug)]
+struct Day;
+
+impl FromStr for Day {
+    type Err = SolutionReport;
+
+    fn from_str(_input: &str) -> SolutionResult<Self> {
+        Ok(Self)
+    }
+}
+
+impl Solution for Day {
+    type Output = usize;
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.solution::<Day>(PuzzleId::aoc(2015, 6))
+        .title(PuzzleId::aoc(2015, 6), "Untitled");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_ornaments::examples!(Day, "tests/scaffold/2015-12-06.examples");
+}
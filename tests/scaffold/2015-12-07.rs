@@ -0,0 +1,39 @@
+//! Day 7: Untitled
+
+use std::str::FromStr;
+
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, ArgSolution, DefaultArgs, Part, SolutionReport, SolutionResult};
+
+#[derive(Debug)]
+struct Day;
+
+impl FromStr for Day {
+    type Err = SolutionReport;
+
+    fn from_str(_input: &str) -> SolutionResult<Self> {
+        Ok(Self)
+    }
+}
+
+impl ArgSolution<u32> for Day {
+    type Output = usize;
+}
+
+impl DefaultArgs<u32> for Day {
+    /// from the puzzle text
+    fn args(_part: Part) -> u32 {
+        Default::default()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.arg_solution::<Day, u32>(PuzzleId::aoc(2015, 7))
+        .title(PuzzleId::aoc(2015, 7), "Untitled");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_ornaments::examples!(Day, u32, "tests/scaffold/2015-12-07.examples");
+}
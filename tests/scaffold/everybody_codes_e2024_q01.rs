@@ -0,0 +1,28 @@
+//! Quest 1: Untitled
+
+use aoc_ornaments::{puzzle::PuzzleId, runner::Registry, Notes, Part, Solution, SolutionResult};
+
+#[derive(Debug)]
+struct Day;
+
+impl Notes<'_> for Day {
+    fn parse_notes(_notes: &str, _part: Part) -> SolutionResult<Self> {
+        Ok(Self)
+    }
+}
+
+impl Solution for Day {
+    type Output = usize;
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.quest::<Day>(PuzzleId::everybody(2024, 1))
+        .title(PuzzleId::everybody(2024, 1), "Untitled");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_ornaments::examples!(notes Day, "tests/scaffold/everybody_codes_e2024_q01.examples");
+}